@@ -0,0 +1,360 @@
+use crate::{Candidate, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, Unit};
+use std::collections::{HashMap, HashSet};
+
+/// A node of an alternating inference chain: a single candidate, or a group of candidates for
+/// one digit in the intersection of a box and a line
+type Node = Vec<Candidate>;
+
+/// The nodes of the current candidates and the links between them, referenced by node index
+struct InferenceGraph {
+    nodes: Vec<Node>,
+    strong: Vec<Vec<usize>>,
+    weak: Vec<Vec<usize>>,
+    /// Candidates that can't be true together with the node
+    weak_candidates: Vec<HashSet<Candidate>>,
+}
+
+/// The kinds of conclusions drawn from alternating inference chains
+#[derive(Clone, Copy, PartialEq)]
+enum ChainKind {
+    /// An open chain: candidates that can't be true together with either end are removed
+    Aic,
+    /// The ends are weakly linked: every weak link of the loop becomes strong as well
+    ContinuousLoop,
+    /// The chain returns to its start with a strong link: the start is true
+    DiscontinuousLoop,
+}
+
+impl InferenceGraph {
+    /// Collect all candidates of the nodes in the chain
+    fn chain_candidates(&self, chain: &[usize]) -> Vec<Candidate> {
+        let mut candidates: Vec<Candidate> = Vec::new();
+        for &node in chain {
+            for &candidate in &self.nodes[node] {
+                if !candidates.contains(&candidate) {
+                    candidates.push(candidate);
+                }
+            }
+        }
+        candidates
+    }
+
+    /// Check that no candidate takes part in more than one node of the chain
+    fn nodes_disjoint(&self, chain: &[usize]) -> bool {
+        let distinct = if chain.first() == chain.last() {
+            &chain[1..]
+        } else {
+            chain
+        };
+        let count: usize = distinct.iter().map(|&node| self.nodes[node].len()).sum();
+        self.chain_candidates(distinct).len() == count
+    }
+
+    /// Collect the candidates that can't be true together with either node
+    fn common_weak_candidates(&self, node1: usize, node2: usize) -> HashSet<Candidate> {
+        self.weak_candidates[node1]
+            .intersection(&self.weak_candidates[node2])
+            .cloned()
+            .collect()
+    }
+}
+
+impl Sudoku {
+    /// Check if two nodes can't both be true: different digits in the same cell, or the same
+    /// digit in cells that all see each other
+    fn nodes_weakly_linked(node1: &Node, node2: &Node) -> bool {
+        let (first1, first2) = (node1[0], node2[0]);
+        if node1.len() == 1
+            && node2.len() == 1
+            && (first1.row, first1.col) == (first2.row, first2.col)
+        {
+            return first1.num != first2.num;
+        }
+        first1.num == first2.num
+            && node1.iter().all(|c1| {
+                node2
+                    .iter()
+                    .all(|c2| Self::cells_see_each_other((c1.row, c1.col), (c2.row, c2.col)))
+            })
+    }
+
+    /// Build the nodes and links of the current candidates. With `grouped`, candidates of a
+    /// digit that share a box and a line form additional grouped nodes.
+    fn build_inference_graph(&self, grouped: bool) -> InferenceGraph {
+        let mut nodes: Vec<Node> = Vec::new();
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.board[row][col] != EMPTY {
+                    continue;
+                }
+                let nums: Vec<u8> = self.candidates[row][col].iter().collect();
+                nodes.extend(
+                    nums.into_iter()
+                        .map(|num| vec![Candidate { row, col, num }]),
+                );
+            }
+        }
+        if grouped {
+            for num in 1..=9 {
+                for box_index in 0..9 {
+                    let (start_row, start_col) = Self::get_box_start_from_index(box_index);
+                    for i in 0..3 {
+                        let lines: [Vec<(usize, usize)>; 2] = [
+                            (0..3).map(|j| (start_row + i, start_col + j)).collect(),
+                            (0..3).map(|j| (start_row + j, start_col + i)).collect(),
+                        ];
+                        for cells in lines {
+                            let group: Node = cells
+                                .into_iter()
+                                .filter(|&(row, col)| self.candidates[row][col].contains(num))
+                                .map(|(row, col)| Candidate { row, col, num })
+                                .collect();
+                            if group.len() >= 2 {
+                                nodes.push(group);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let mut index: HashMap<Node, usize> = HashMap::new();
+        for (i, node) in nodes.iter().enumerate() {
+            let mut key = node.clone();
+            key.sort_by_key(|c| (c.row, c.col));
+            index.insert(key, i);
+        }
+
+        // Strong links: bivalue cells and the only two nodes of a digit within a unit
+        let mut strong_links: HashSet<(usize, usize)> = HashSet::new();
+        for (row, col) in self.cells_with_candidate_count(2) {
+            let nums: Vec<u8> = self.candidates[row][col].iter().collect();
+            let node1 = index[&vec![Candidate {
+                row,
+                col,
+                num: nums[0],
+            }]];
+            let node2 = index[&vec![Candidate {
+                row,
+                col,
+                num: nums[1],
+            }]];
+            strong_links.insert((node1, node2));
+        }
+        let max_cells = if grouped { 6 } else { 2 };
+        for num in 1..=9 {
+            for unit in [Unit::Row, Unit::Column, Unit::Box] {
+                for unit_index in 0..9 {
+                    let cells: Vec<Candidate> = Self::get_unit_cells(&unit, unit_index)
+                        .into_iter()
+                        .filter(|&(row, col)| self.candidates[row][col].contains(num))
+                        .map(|(row, col)| Candidate { row, col, num })
+                        .collect();
+                    if cells.len() < 2 || cells.len() > max_cells {
+                        continue;
+                    }
+                    // Split the cells into two parts that both form a node; the first cell
+                    // always goes to the first part
+                    for mask in (1..(1usize << cells.len()) - 1).step_by(2) {
+                        let (mut part1, mut part2): (Node, Node) = (Vec::new(), Vec::new());
+                        for (i, &candidate) in cells.iter().enumerate() {
+                            if mask & (1 << i) != 0 {
+                                part1.push(candidate);
+                            } else {
+                                part2.push(candidate);
+                            }
+                        }
+                        part1.sort_by_key(|c| (c.row, c.col));
+                        part2.sort_by_key(|c| (c.row, c.col));
+                        if let (Some(&node1), Some(&node2)) = (index.get(&part1), index.get(&part2))
+                        {
+                            strong_links.insert((node1.min(node2), node1.max(node2)));
+                        }
+                    }
+                }
+            }
+        }
+        let mut strong: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        let mut sorted_links: Vec<(usize, usize)> = strong_links.into_iter().collect();
+        sorted_links.sort();
+        for (node1, node2) in sorted_links {
+            strong[node1].push(node2);
+            strong[node2].push(node1);
+        }
+
+        // Weak links: nodes that can't both be true
+        let mut weak: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        let mut weak_candidates: Vec<HashSet<Candidate>> = vec![HashSet::new(); nodes.len()];
+        for i in 0..nodes.len() {
+            for j in (i + 1)..nodes.len() {
+                if Self::nodes_weakly_linked(&nodes[i], &nodes[j]) {
+                    weak[i].push(j);
+                    weak[j].push(i);
+                    if nodes[j].len() == 1 {
+                        weak_candidates[i].insert(nodes[j][0]);
+                    }
+                    if nodes[i].len() == 1 {
+                        weak_candidates[j].insert(nodes[i][0]);
+                    }
+                }
+            }
+        }
+        InferenceGraph {
+            nodes,
+            strong,
+            weak,
+            weak_candidates,
+        }
+    }
+
+    /// Check the chain for conclusions of the given kind and collect them into `result`
+    fn collect_chain_conclusions(
+        &self,
+        graph: &InferenceGraph,
+        chain: &[usize],
+        kind: ChainKind,
+        result: &mut RemovalResult,
+    ) {
+        let (first, last) = (chain[0], chain[chain.len() - 1]);
+        if !graph.nodes_disjoint(chain) {
+            return;
+        }
+        let chain_candidates = graph.chain_candidates(chain);
+        match kind {
+            ChainKind::Aic => {
+                if first == last {
+                    return;
+                }
+                result.candidates_about_to_be_removed = graph
+                    .common_weak_candidates(first, last)
+                    .into_iter()
+                    .filter(|candidate| !chain_candidates.contains(candidate))
+                    .collect();
+            }
+            ChainKind::ContinuousLoop => {
+                if first == last || !graph.weak[last].contains(&first) {
+                    return;
+                }
+                let mut weak_links: Vec<(usize, usize)> = chain[1..]
+                    .chunks_exact(2)
+                    .map(|link| (link[0], link[1]))
+                    .collect();
+                weak_links.push((last, first));
+                for (node1, node2) in weak_links {
+                    result.candidates_about_to_be_removed.extend(
+                        graph
+                            .common_weak_candidates(node1, node2)
+                            .into_iter()
+                            .filter(|candidate| !chain_candidates.contains(candidate)),
+                    );
+                }
+            }
+            ChainKind::DiscontinuousLoop => {
+                if first != last || graph.nodes[first].len() != 1 {
+                    return;
+                }
+                let start = graph.nodes[first][0];
+                *result = self.collect_set_num(start.num, start.row, start.col);
+            }
+        }
+        if result.will_remove_candidates() {
+            result.candidates_affected = chain_candidates;
+            result.chain = chain
+                .iter()
+                .map(|&node| graph.nodes[node].clone())
+                .collect();
+            if kind == ChainKind::ContinuousLoop {
+                result.chain.push(graph.nodes[first].clone());
+            }
+        }
+    }
+
+    /// Search alternating inference chains from every node for conclusions of the given kind
+    fn find_inference_chain(
+        &self,
+        strategy: Strategy,
+        kind: ChainKind,
+        grouped: bool,
+    ) -> StrategyResult {
+        let graph = self.build_inference_graph(grouped);
+        let strong_links = |node: &usize| -> Vec<usize> { graph.strong[*node].clone() };
+        let weak_links = |node: &usize| -> Vec<usize> { graph.weak[*node].clone() };
+        let mut result = RemovalResult::empty();
+        for start in 0..graph.nodes.len() {
+            let chain = self.search_chain(start, &strong_links, &weak_links, &mut |chain| {
+                result.clear();
+                self.collect_chain_conclusions(&graph, chain, kind, &mut result);
+                result.will_remove_candidates()
+            });
+            if chain.is_some() {
+                return StrategyResult {
+                    strategy,
+                    removals: result,
+                };
+            }
+        }
+        StrategyResult::new(strategy)
+    }
+
+    /// Find and resolve Continuous Nice Loops.
+    /// An alternating inference chain whose ends are weakly linked closes a loop in which
+    /// every node alternates between true and false. Each weak link of the loop then holds
+    /// exactly one true node, so all candidates outside the loop that can't be true together
+    /// with either node of a weak link are removed.
+    pub fn find_continuous_nice_loop(&self) -> StrategyResult {
+        log::info!("Finding Continuous Nice Loop");
+        self.find_inference_chain(
+            Strategy::ContinuousNiceLoop,
+            ChainKind::ContinuousLoop,
+            false,
+        )
+    }
+
+    /// Find and resolve Discontinuous Nice Loops.
+    /// If the start candidate being false leads back to itself through a strong link, it
+    /// contradicts itself and has to be true.
+    pub fn find_discontinuous_nice_loop(&self) -> StrategyResult {
+        log::info!("Finding Discontinuous Nice Loop");
+        self.find_inference_chain(
+            Strategy::DiscontinuousNiceLoop,
+            ChainKind::DiscontinuousLoop,
+            false,
+        )
+    }
+
+    /// Find and resolve Alternating Inference Chains (AIC).
+    /// Strong links (conjugate pairs and bivalue cells) and weak links (peers of the same
+    /// digit and candidates of the same cell) alternate, starting and ending with a strong
+    /// link. One of the ends has to be true, so every candidate that can't be true together
+    /// with either end is removed.
+    pub fn find_aic(&self) -> StrategyResult {
+        log::info!("Finding AIC");
+        self.find_inference_chain(Strategy::Aic, ChainKind::Aic, false)
+    }
+
+    /// Find and resolve Continuous Nice Loops with grouped nodes: candidates of a digit
+    /// within the intersection of a box and a line act as a single node.
+    pub fn find_grouped_continuous_nice_loop(&self) -> StrategyResult {
+        log::info!("Finding Grouped Continuous Nice Loop");
+        self.find_inference_chain(
+            Strategy::GroupedContinuousNiceLoop,
+            ChainKind::ContinuousLoop,
+            true,
+        )
+    }
+
+    /// Find and resolve Discontinuous Nice Loops with grouped nodes.
+    pub fn find_grouped_discontinuous_nice_loop(&self) -> StrategyResult {
+        log::info!("Finding Grouped Discontinuous Nice Loop");
+        self.find_inference_chain(
+            Strategy::GroupedDiscontinuousNiceLoop,
+            ChainKind::DiscontinuousLoop,
+            true,
+        )
+    }
+
+    /// Find and resolve Alternating Inference Chains with grouped nodes.
+    pub fn find_grouped_aic(&self) -> StrategyResult {
+        log::info!("Finding Grouped AIC");
+        self.find_inference_chain(Strategy::GroupedAic, ChainKind::Aic, true)
+    }
+}
@@ -0,0 +1,328 @@
+use crate::{
+    Candidate, CandidateSet, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, Unit,
+};
+use std::collections::HashSet;
+
+/// An almost locked set (ALS): `n` cells within one unit that hold `n + 1` candidates.
+/// Removing any one of its digits leaves a locked set.
+pub(crate) struct Als {
+    cells: Vec<(usize, usize)>,
+    nums: CandidateSet,
+    /// Cells of the set holding each digit, indexed by digit
+    num_cells: [Vec<(usize, usize)>; 10],
+}
+
+impl Als {
+    fn new(sudoku: &Sudoku, cells: Vec<(usize, usize)>) -> Self {
+        let nums = cells
+            .iter()
+            .flat_map(|&(row, col)| sudoku.candidates[row][col].iter())
+            .collect();
+        let num_cells = std::array::from_fn(|num| {
+            cells
+                .iter()
+                .filter(|&&(row, col)| num > 0 && sudoku.candidates[row][col].contains(num as u8))
+                .cloned()
+                .collect()
+        });
+        Als {
+            cells,
+            nums,
+            num_cells,
+        }
+    }
+
+    fn overlaps(&self, other: &Als) -> bool {
+        self.cells.iter().any(|cell| other.cells.contains(cell))
+    }
+
+    /// Digits of both sets whose cells all see each other: at most one of the sets can hold
+    /// such a restricted common candidate (RCC).
+    fn restricted_common_candidates(&self, other: &Als) -> Vec<u8> {
+        if self.overlaps(other) {
+            return Vec::new();
+        }
+        self.nums
+            .intersection(&other.nums)
+            .iter()
+            .filter(|&num| {
+                self.num_cells[num as usize].iter().all(|&cell1| {
+                    other.num_cells[num as usize]
+                        .iter()
+                        .all(|&cell2| Sudoku::cells_see_each_other(cell1, cell2))
+                })
+            })
+            .collect()
+    }
+
+    /// Digits of both sets, except the given ones
+    fn common_nums(&self, other: &Als, except: &[u8]) -> Vec<u8> {
+        self.nums
+            .intersection(&other.nums)
+            .iter()
+            .filter(|num| !except.contains(num))
+            .collect()
+    }
+}
+
+impl Sudoku {
+    /// Enumerate the almost locked sets of all units, each set of cells only once
+    pub(crate) fn find_almost_locked_sets(&self) -> Vec<Als> {
+        let mut sets: Vec<Als> = Vec::new();
+        let mut seen: HashSet<Vec<(usize, usize)>> = HashSet::new();
+        for unit in [Unit::Row, Unit::Column, Unit::Box] {
+            for index in 0..9 {
+                let cells: Vec<(usize, usize)> = Self::get_unit_cells(&unit, index)
+                    .into_iter()
+                    .filter(|&(row, col)| self.board[row][col] == EMPTY)
+                    .collect();
+                for size in 1..cells.len() {
+                    for subset in Self::combinations(&cells, size) {
+                        let nums: CandidateSet = subset
+                            .iter()
+                            .flat_map(|&(row, col)| self.candidates[row][col].iter())
+                            .collect();
+                        if nums.len() != size + 1 {
+                            continue;
+                        }
+                        let mut key = subset.clone();
+                        key.sort();
+                        if seen.insert(key) {
+                            sets.push(Als::new(self, subset));
+                        }
+                    }
+                }
+            }
+        }
+        sets
+    }
+
+    /// Remove `num` from all cells that see every cell of the given sets holding `num`
+    fn remove_candidate_seen_by_sets(&self, sets: &[&Als], num: u8, result: &mut RemovalResult) {
+        let cells: Vec<(usize, usize)> = sets
+            .iter()
+            .flat_map(|als| als.num_cells[num as usize].iter().cloned())
+            .collect();
+        self.remove_candidate_seen_by(&cells, num, result);
+    }
+
+    /// Report the candidates of the sets as affected, colored by set
+    fn push_almost_locked_sets(&self, sets: &[&Als], result: &mut RemovalResult) {
+        for (index, als) in sets.iter().enumerate() {
+            for &(row, col) in &als.cells {
+                for num in self.candidates[row][col] {
+                    let candidate = Candidate { row, col, num };
+                    result.candidates_affected.push(candidate);
+                    result.colored_candidates.push((candidate, index));
+                }
+            }
+        }
+    }
+
+    /// Find and resolve ALS-XZ.
+    /// Two almost locked sets A and B share a restricted common candidate `x`: since at most
+    /// one of them holds `x`, the other one is locked. So for every other common digit `z`,
+    /// one of the sets holds `z`, and `z` can be removed from all cells that see every `z`
+    /// in both sets. If the sets are doubly linked by two RCCs, both sets are locked: the
+    /// RCCs can be removed from all cells seeing every instance in both sets, and the other
+    /// digits from all cells seeing every instance within their set.
+    pub fn find_als_xz(&self) -> StrategyResult {
+        log::info!("Finding ALS-XZ");
+        let sets = self.find_almost_locked_sets();
+        let mut result = RemovalResult::empty();
+        for (i, a) in sets.iter().enumerate() {
+            for b in sets.iter().skip(i + 1) {
+                let rcc = a.restricted_common_candidates(b);
+                if rcc.is_empty() {
+                    continue;
+                }
+                for z in a.common_nums(b, &rcc) {
+                    self.remove_candidate_seen_by_sets(&[a, b], z, &mut result);
+                }
+                if rcc.len() >= 2 {
+                    for &x in &rcc {
+                        self.remove_candidate_seen_by_sets(&[a, b], x, &mut result);
+                    }
+                    for als in [a, b] {
+                        for num in als.nums {
+                            if !rcc.contains(&num) {
+                                self.remove_candidate_seen_by_sets(&[als], num, &mut result);
+                            }
+                        }
+                    }
+                }
+                if result.will_remove_candidates() {
+                    self.push_almost_locked_sets(&[a, b], &mut result);
+                    return StrategyResult {
+                        strategy: Strategy::AlsXz,
+                        removals: result,
+                    };
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::AlsXz,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve ALS-XY-Wings.
+    /// A pivot set C shares the restricted common candidate `x` with set A and a different
+    /// one `y` with set B. If A doesn't hold `x` and B doesn't hold `y`, C would need both, so
+    /// one of A and B is locked. Any digit `z` common to A and B can be removed from all
+    /// cells that see every `z` in both sets.
+    pub fn find_als_xy_wing(&self) -> StrategyResult {
+        log::info!("Finding ALS-XY-Wing");
+        let sets = self.find_almost_locked_sets();
+        let mut result = RemovalResult::empty();
+        let links: Vec<Vec<(usize, Vec<u8>)>> = sets
+            .iter()
+            .map(|als| {
+                sets.iter()
+                    .enumerate()
+                    .map(|(index, other)| (index, als.restricted_common_candidates(other)))
+                    .filter(|(_, rcc)| !rcc.is_empty())
+                    .collect()
+            })
+            .collect();
+        for (c, pivot) in sets.iter().enumerate() {
+            for (i, (a, rcc_a)) in links[c].iter().enumerate() {
+                for (b, rcc_b) in links[c].iter().skip(i + 1) {
+                    let (wing_a, wing_b) = (&sets[*a], &sets[*b]);
+                    if wing_a.overlaps(wing_b) {
+                        continue;
+                    }
+                    for &x in rcc_a {
+                        for &y in rcc_b {
+                            if x == y {
+                                continue;
+                            }
+                            for z in wing_a.common_nums(wing_b, &[x, y]) {
+                                self.remove_candidate_seen_by_sets(
+                                    &[wing_a, wing_b],
+                                    z,
+                                    &mut result,
+                                );
+                            }
+                            if result.will_remove_candidates() {
+                                self.push_almost_locked_sets(&[pivot, wing_a, wing_b], &mut result);
+                                return StrategyResult {
+                                    strategy: Strategy::AlsXyWing,
+                                    removals: result,
+                                };
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::AlsXyWing,
+            removals: result,
+        }
+    }
+
+    /// Assign an almost locked set (petal) to each remaining digit of the stem, keeping
+    /// the cells that see every `z` of the petals chosen so far as `targets`.
+    fn find_death_blossom_petals<'a>(
+        &self,
+        petals: &[Vec<&'a Als>],
+        z: u8,
+        targets: Vec<(usize, usize)>,
+        chosen: &mut Vec<&'a Als>,
+    ) -> Option<Vec<(usize, usize)>> {
+        if targets.is_empty() {
+            return None;
+        }
+        if chosen.len() == petals.len() {
+            return Some(targets);
+        }
+        for &als in &petals[chosen.len()] {
+            if !als.nums.contains(z) || chosen.iter().any(|petal| petal.overlaps(als)) {
+                continue;
+            }
+            let remaining: Vec<(usize, usize)> = targets
+                .iter()
+                .filter(|&&cell| {
+                    als.num_cells[z as usize]
+                        .iter()
+                        .all(|&z_cell| Self::cells_see_each_other(z_cell, cell))
+                })
+                .cloned()
+                .collect();
+            chosen.push(als);
+            if let Some(targets) = self.find_death_blossom_petals(petals, z, remaining, chosen) {
+                return Some(targets);
+            }
+            chosen.pop();
+        }
+        None
+    }
+
+    /// Find and resolve Death Blossoms.
+    /// Every candidate `d` of a stem cell is linked to an almost locked set (petal) whose
+    /// `d` cells all see the stem. Whatever the stem's value, the corresponding petal loses
+    /// `d` and is locked, so a digit `z` common to all petals can be removed from all cells
+    /// that see every `z` of the petals.
+    pub fn find_death_blossom(&self) -> StrategyResult {
+        log::info!("Finding Death Blossom");
+        let sets = self.find_almost_locked_sets();
+        let mut result = RemovalResult::empty();
+        for row in 0..9 {
+            for col in 0..9 {
+                let stem = (row, col);
+                if self.board[row][col] != EMPTY || self.candidates[row][col].len() < 2 {
+                    continue;
+                }
+                let stem_nums: Vec<u8> = self.candidates[row][col].iter().collect();
+                let petals: Vec<Vec<&Als>> = stem_nums
+                    .iter()
+                    .map(|&num| {
+                        sets.iter()
+                            .filter(|als| {
+                                !als.cells.contains(&stem)
+                                    && als.nums.contains(num)
+                                    && als.num_cells[num as usize]
+                                        .iter()
+                                        .all(|&cell| Self::cells_see_each_other(cell, stem))
+                            })
+                            .collect()
+                    })
+                    .collect();
+                for z in 1..=9 {
+                    if stem_nums.contains(&z) {
+                        continue;
+                    }
+                    let targets: Vec<(usize, usize)> = (0..81)
+                        .map(|i| (i / 9, i % 9))
+                        .filter(|&(r, c)| self.candidates[r][c].contains(z))
+                        .collect();
+                    let mut chosen = Vec::new();
+                    if let Some(targets) =
+                        self.find_death_blossom_petals(&petals, z, targets, &mut chosen)
+                    {
+                        for (r, c) in targets {
+                            result.candidates_about_to_be_removed.insert(Candidate {
+                                row: r,
+                                col: c,
+                                num: z,
+                            });
+                        }
+                        let stem_cell = Als::new(self, vec![stem]);
+                        let mut sets = vec![&stem_cell];
+                        sets.extend(chosen);
+                        self.push_almost_locked_sets(&sets, &mut result);
+                        return StrategyResult {
+                            strategy: Strategy::DeathBlossom,
+                            removals: result,
+                        };
+                    }
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::DeathBlossom,
+            removals: result,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Candidate, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, Unit};
+
+impl Sudoku {
+    /// Check if the board can't be solved any more: an empty cell without candidates, a digit
+    /// placed twice in a unit, or a digit that has no place left in a unit.
+    pub(crate) fn has_contradiction(&self) -> bool {
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.board[row][col] == EMPTY && self.candidates[row][col].is_empty() {
+                    return true;
+                }
+            }
+        }
+        for unit in [Unit::Row, Unit::Column, Unit::Box] {
+            for index in 0..9 {
+                let cells = Self::get_unit_cells(&unit, index);
+                for num in 1..=9 {
+                    let placed = cells
+                        .iter()
+                        .filter(|&&(row, col)| self.board[row][col] == num)
+                        .count();
+                    let open = cells
+                        .iter()
+                        .any(|&(row, col)| self.candidates[row][col].contains(num));
+                    if placed > 1 || (placed == 0 && !open) {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// Extra difficulty of a guess at the given nesting depth: every level of guesses within
+    /// a guess adds the difficulty of another guess.
+    pub(crate) fn trial_depth_difficulty(&self, depth: usize) -> i32 {
+        self.strategy_effort(Strategy::BowmansBingo)
+            .unwrap_or_default()
+            * depth.saturating_sub(1) as i32
+    }
+
+    /// Set the candidate on a copy of the board and keep solving with all strategies until the
+    /// board is solved, gets stuck or runs into a contradiction. Within the trial, guesses may
+    /// be nested up to `depth - 1` levels.
+    fn trial_leads_to_contradiction(&self, candidate: Candidate, depth: usize) -> bool {
+        let mut trial = self.clone();
+        trial.undo_stack.clear();
+        trial.options.max_trial_depth = depth - 1;
+        let guess = StrategyResult {
+            strategy: Strategy::BowmansBingo,
+            removals: trial.collect_set_num(candidate.num, candidate.row, candidate.col),
+        };
+        trial.apply(&guess);
+        loop {
+            if trial.has_contradiction() {
+                return true;
+            }
+            if trial.is_solved() {
+                return false;
+            }
+            let result = trial.next_step();
+            if result.strategy == Strategy::None {
+                return false;
+            }
+            trial.apply(&result);
+            trial.undo_stack.clear();
+        }
+    }
+
+    /// Find and resolve Bowman's Bingo (trial and error).
+    /// Guess a candidate and follow its consequences with all other strategies. If the guess
+    /// leads to a contradiction, the candidate is removed. If no single guess does, guesses
+    /// are nested within guesses, up to `max_trial_depth` levels (see `SolverOptions`).
+    /// Candidates of cells with few candidates are tried first.
+    pub fn find_bowmans_bingo(&self) -> StrategyResult {
+        log::info!("Finding Bowman's Bingo");
+        let mut candidates: Vec<Candidate> = Vec::new();
+        for count in 2..=9 {
+            for (row, col) in self.cells_with_candidate_count(count) {
+                let nums: Vec<u8> = self.candidates[row][col].iter().collect();
+                candidates.extend(nums.into_iter().map(|num| Candidate { row, col, num }));
+            }
+        }
+        let mut result = RemovalResult::empty();
+        for depth in 1..=self.options.max_trial_depth {
+            for &candidate in &candidates {
+                if self.trial_leads_to_contradiction(candidate, depth) {
+                    result.candidates_about_to_be_removed.insert(candidate);
+                    result.candidates_affected.push(candidate);
+                    result.trial_depth = depth;
+                    return StrategyResult {
+                        strategy: Strategy::BowmansBingo,
+                        removals: result,
+                    };
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::BowmansBingo,
+            removals: result,
+        }
+    }
+}
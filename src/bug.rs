@@ -0,0 +1,63 @@
+use crate::{EMPTY, Strategy, StrategyResult, Sudoku, Unit};
+
+impl Sudoku {
+    /// Count how often `num` is a candidate in the given unit
+    fn count_candidate_in_unit(&self, unit: &Unit, index: usize, num: u8) -> usize {
+        Self::get_unit_cells(unit, index)
+            .into_iter()
+            .filter(|&(row, col)| self.candidates[row][col].contains(num))
+            .count()
+    }
+
+    /// Find and resolve a BUG+1 (Bivalue Universal Grave plus one).
+    /// If all unsolved cells but one hold exactly two candidates, and every candidate appears
+    /// exactly twice in each unit except for one digit of the single trivalue cell, the puzzle
+    /// would have two solutions without that digit. So the digit is placed in the trivalue cell.
+    pub fn find_bug_plus_one(&self) -> StrategyResult {
+        log::info!("Finding BUG+1");
+        let mut trivalue = None;
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.board[row][col] != EMPTY {
+                    continue;
+                }
+                match self.candidates[row][col].len() {
+                    2 => {}
+                    3 if trivalue.is_none() => trivalue = Some((row, col)),
+                    _ => return StrategyResult::new(Strategy::BugPlusOne),
+                }
+            }
+        }
+        let Some((row, col)) = trivalue else {
+            return StrategyResult::new(Strategy::BugPlusOne);
+        };
+        // The digit breaking the grave appears three times in the trivalue cell's row
+        let Some(num) = self.candidates[row][col]
+            .iter()
+            .find(|&num| self.count_candidate_in_unit(&Unit::Row, row, num) == 3)
+        else {
+            return StrategyResult::new(Strategy::BugPlusOne);
+        };
+        let box_index = Self::get_box_index(row, col);
+        for unit in [Unit::Row, Unit::Column, Unit::Box] {
+            for index in 0..9 {
+                let contains_trivalue = match unit {
+                    Unit::Row => index == row,
+                    Unit::Column => index == col,
+                    Unit::Box => index == box_index,
+                };
+                for n in 1..=9 {
+                    let expected = if contains_trivalue && n == num { 3 } else { 2 };
+                    let count = self.count_candidate_in_unit(&unit, index, n);
+                    if count != 0 && count != expected {
+                        return StrategyResult::new(Strategy::BugPlusOne);
+                    }
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::BugPlusOne,
+            removals: self.collect_set_num(num, row, col),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt;
+
+/// The candidates of a cell (or any set of digits) as a bitmask: bit `n` is set if digit
+/// `n` is part of the set. Cheap to copy, and iterates in ascending order.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CandidateSet(u16);
+
+impl CandidateSet {
+    const ALL: u16 = 0b11_1111_1110;
+
+    /// An empty set
+    pub const fn new() -> Self {
+        CandidateSet(0)
+    }
+
+    /// The set of all digits 1 to 9
+    pub const fn all() -> Self {
+        CandidateSet(Self::ALL)
+    }
+
+    /// A set with a single digit
+    pub const fn single(num: u8) -> Self {
+        debug_assert!(num >= 1 && num <= 9);
+        CandidateSet(1 << num)
+    }
+
+    pub const fn contains(&self, num: u8) -> bool {
+        debug_assert!(num >= 1 && num <= 9);
+        self.0 & (1 << num) != 0
+    }
+
+    /// Add a digit; returns whether it was not part of the set before
+    pub fn insert(&mut self, num: u8) -> bool {
+        debug_assert!((1..=9).contains(&num));
+        let added = !self.contains(num);
+        self.0 |= 1 << num;
+        added
+    }
+
+    /// Remove a digit; returns whether it was part of the set before
+    pub fn remove(&mut self, num: u8) -> bool {
+        debug_assert!((1..=9).contains(&num));
+        let removed = self.contains(num);
+        self.0 &= !(1 << num);
+        removed
+    }
+
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn union(&self, other: &CandidateSet) -> CandidateSet {
+        CandidateSet(self.0 | other.0)
+    }
+
+    pub const fn intersection(&self, other: &CandidateSet) -> CandidateSet {
+        CandidateSet(self.0 & other.0)
+    }
+
+    /// Digits of this set that are not part of `other`
+    pub const fn difference(&self, other: &CandidateSet) -> CandidateSet {
+        CandidateSet(self.0 & !other.0)
+    }
+
+    pub const fn is_disjoint(&self, other: &CandidateSet) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    pub const fn is_subset(&self, other: &CandidateSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// The bitmask of the set, bit `n` for digit `n`
+    pub const fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// The smallest digit of the set
+    pub fn first(&self) -> Option<u8> {
+        self.iter().next()
+    }
+
+    /// Iterate over the digits in ascending order
+    pub fn iter(&self) -> CandidateSetIter {
+        CandidateSetIter(self.0)
+    }
+}
+
+impl fmt::Debug for CandidateSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over the digits of a `CandidateSet`, in ascending order
+pub struct CandidateSetIter(u16);
+
+impl Iterator for CandidateSetIter {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.0 == 0 {
+            return None;
+        }
+        let num = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+        Some(num)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CandidateSetIter {}
+
+impl IntoIterator for CandidateSet {
+    type Item = u8;
+    type IntoIter = CandidateSetIter;
+
+    fn into_iter(self) -> CandidateSetIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &CandidateSet {
+    type Item = u8;
+    type IntoIter = CandidateSetIter;
+
+    fn into_iter(self) -> CandidateSetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<u8> for CandidateSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut set = CandidateSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<u8> for CandidateSet {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for num in iter {
+            self.insert(num);
+        }
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for CandidateSet {
+    fn from(nums: [u8; N]) -> Self {
+        nums.into_iter().collect()
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Candidate, RemovalResult, Strategy, StrategyResult, Sudoku};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// A node of the breadth-first chain search
+struct ChainNode<T> {
+    candidate: T,
+    /// Whether the link leading to this node is strong
+    after_strong: bool,
+    parent: Option<usize>,
+    length: usize,
+}
+
+impl Sudoku {
+    /// Search the shortest chain starting at `start` that `accept` agrees to.
+    /// The chain alternates strong and weak links, starting and ending with a strong link,
+    /// and holds at most `max_chain_length` nodes (see `SolverOptions`). Since either the
+    /// first or the last node of such a chain has to be true, `accept` can check for
+    /// candidates that see both ends. All nodes are distinct, except that the chain may
+    /// return to its start to form a loop.
+    pub(crate) fn search_chain<T: Copy + Eq + Hash>(
+        &self,
+        start: T,
+        strong_links: &dyn Fn(&T) -> Vec<T>,
+        weak_links: &dyn Fn(&T) -> Vec<T>,
+        accept: &mut dyn FnMut(&[T]) -> bool,
+    ) -> Option<Vec<T>> {
+        let mut nodes = vec![ChainNode {
+            candidate: start,
+            after_strong: false,
+            parent: None,
+            length: 1,
+        }];
+        let mut visited: HashSet<(T, bool)> = HashSet::new();
+        visited.insert((start, false));
+        let mut next = 0;
+        while next < nodes.len() {
+            let index = next;
+            next += 1;
+            let candidate = nodes[index].candidate;
+            let after_strong = nodes[index].after_strong;
+            let length = nodes[index].length;
+            if after_strong && length >= 4 {
+                let chain = Self::chain_path(&nodes, index);
+                let distinct = if chain.first() == chain.last() {
+                    &chain[1..]
+                } else {
+                    &chain[..]
+                };
+                let unique: HashSet<&T> = distinct.iter().collect();
+                if unique.len() == distinct.len() && accept(&chain) {
+                    return Some(chain);
+                }
+            }
+            if length >= self.options.max_chain_length {
+                continue;
+            }
+            let neighbours = if after_strong {
+                weak_links(&candidate)
+            } else {
+                strong_links(&candidate)
+            };
+            for neighbour in neighbours {
+                if visited.insert((neighbour, !after_strong)) {
+                    nodes.push(ChainNode {
+                        candidate: neighbour,
+                        after_strong: !after_strong,
+                        parent: Some(index),
+                        length: length + 1,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Collect the candidates from the start of the search to the node at `index`
+    fn chain_path<T: Copy>(nodes: &[ChainNode<T>], index: usize) -> Vec<T> {
+        let mut chain = Vec::new();
+        let mut current = Some(index);
+        while let Some(index) = current {
+            chain.push(nodes[index].candidate);
+            current = nodes[index].parent;
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Collect the candidates for `num` in cells that see both ends of the chain
+    fn chain_end_removals(&self, chain: &[Candidate], num: u8) -> HashSet<Candidate> {
+        let first = chain.first().unwrap();
+        let last = chain.last().unwrap();
+        let mut result = RemovalResult::empty();
+        self.remove_candidate_seen_by(
+            &[(first.row, first.col), (last.row, last.col)],
+            num,
+            &mut result,
+        );
+        result.candidates_about_to_be_removed
+    }
+
+    /// Extra difficulty of a chain with `length` nodes, scaled like Sudoku Explainer does:
+    /// chains up to 4 nodes add nothing, every time the length exceeds the next step of the
+    /// series 4, 6, 8, 12, 16, 24, ... another `SolverConfig::chain_bonus_step` is added.
+    pub(crate) fn chain_length_difficulty(&self, length: usize) -> i32 {
+        let mut added = 0;
+        let mut ceiling = 4;
+        let mut odd = false;
+        while length > ceiling {
+            added += self.config.chain_bonus_step;
+            ceiling = if odd {
+                ceiling * 4 / 3
+            } else {
+                ceiling * 3 / 2
+            };
+            odd = !odd;
+        }
+        added
+    }
+
+    fn chain_result(
+        strategy: Strategy,
+        chain: Vec<Candidate>,
+        removals: HashSet<Candidate>,
+    ) -> StrategyResult {
+        let mut result = RemovalResult::empty();
+        result.candidates_about_to_be_removed = removals;
+        result.chain = chain.iter().map(|&candidate| vec![candidate]).collect();
+        result.candidates_affected = chain;
+        StrategyResult {
+            strategy,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve X-Chains.
+    /// A chain of candidates for a single digit with alternating strong and weak links,
+    /// starting and ending with a strong link. The digit is true at one of the ends, so it
+    /// can be removed from all cells that see both ends.
+    pub fn find_x_chain(&self) -> StrategyResult {
+        log::info!("Finding X-Chain");
+        for num in 1..=9 {
+            let links = self.find_strong_links(num);
+            let candidates: Vec<Candidate> = (0..81)
+                .map(|i| Candidate {
+                    row: i / 9,
+                    col: i % 9,
+                    num,
+                })
+                .filter(|c| self.candidates[c.row][c.col].contains(num))
+                .collect();
+            let strong_links = |candidate: &Candidate| -> Vec<Candidate> {
+                links
+                    .iter()
+                    .filter_map(|(link, _)| {
+                        if link.base == *candidate {
+                            Some(link.top)
+                        } else if link.top == *candidate {
+                            Some(link.base)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect()
+            };
+            let weak_links = |candidate: &Candidate| -> Vec<Candidate> {
+                candidates
+                    .iter()
+                    .filter(|c| {
+                        Self::cells_see_each_other((c.row, c.col), (candidate.row, candidate.col))
+                    })
+                    .cloned()
+                    .collect()
+            };
+            for &start in &candidates {
+                let mut removals = HashSet::new();
+                let chain = self.search_chain(start, &strong_links, &weak_links, &mut |chain| {
+                    if chain.first() == chain.last() {
+                        return false;
+                    }
+                    removals = self.chain_end_removals(chain, num);
+                    !removals.is_empty()
+                });
+                if let Some(chain) = chain {
+                    return Self::chain_result(Strategy::XChain, chain, removals);
+                }
+            }
+        }
+        StrategyResult::new(Strategy::XChain)
+    }
+
+    /// Find and resolve XY-Chains.
+    /// A chain through bivalue cells: within each cell the link between its two candidates
+    /// is strong, between cells the same digit forms a weak link. If the chain starts and ends
+    /// with the same digit `z` in different cells, one of the ends is `z`, so `z` can be
+    /// removed from all cells that see both ends.
+    pub fn find_xy_chain(&self) -> StrategyResult {
+        log::info!("Finding XY-Chain");
+        let bivalue_cells = self.cells_with_candidate_count(2);
+        let strong_links = |candidate: &Candidate| -> Vec<Candidate> {
+            self.candidates[candidate.row][candidate.col]
+                .iter()
+                .filter(|&num| num != candidate.num)
+                .map(|num| Candidate {
+                    row: candidate.row,
+                    col: candidate.col,
+                    num,
+                })
+                .collect()
+        };
+        let weak_links = |candidate: &Candidate| -> Vec<Candidate> {
+            bivalue_cells
+                .iter()
+                .filter(|&&(row, col)| {
+                    self.candidates[row][col].contains(candidate.num)
+                        && Self::cells_see_each_other((row, col), (candidate.row, candidate.col))
+                })
+                .map(|&(row, col)| Candidate {
+                    row,
+                    col,
+                    num: candidate.num,
+                })
+                .collect()
+        };
+        for &(row, col) in &bivalue_cells {
+            for num in self.candidates[row][col] {
+                let start = Candidate { row, col, num };
+                let mut removals = HashSet::new();
+                let chain = self.search_chain(start, &strong_links, &weak_links, &mut |chain| {
+                    let last = chain.last().unwrap();
+                    if last.num != num || (last.row, last.col) == (row, col) {
+                        return false;
+                    }
+                    removals = self.chain_end_removals(chain, num);
+                    !removals.is_empty()
+                });
+                if let Some(chain) = chain {
+                    return Self::chain_result(Strategy::XYChain, chain, removals);
+                }
+            }
+        }
+        StrategyResult::new(Strategy::XYChain)
+    }
+}
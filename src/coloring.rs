@@ -0,0 +1,229 @@
+use crate::{Candidate, RemovalResult, Strategy, StrategyResult, Sudoku};
+
+/// A cluster of candidates for one digit connected by strong links, colored with two
+/// alternating colors: exactly one of both color classes is true.
+struct ColorCluster {
+    num: u8,
+    colors: [Vec<(usize, usize)>; 2],
+}
+
+impl ColorCluster {
+    fn cells(&self) -> impl Iterator<Item = &(usize, usize)> {
+        self.colors[0].iter().chain(self.colors[1].iter())
+    }
+
+    fn contains(&self, cell: &(usize, usize)) -> bool {
+        self.colors[0].contains(cell) || self.colors[1].contains(cell)
+    }
+
+    /// Check if any cell of the color class sees the given cell
+    fn sees(&self, color: usize, cell: (usize, usize)) -> bool {
+        self.colors[color]
+            .iter()
+            .any(|&colored| Sudoku::cells_see_each_other(colored, cell))
+    }
+
+    /// Check if any cell of the color class sees any cell of another cluster's color class
+    fn sees_color(&self, color: usize, other: &ColorCluster, other_color: usize) -> bool {
+        other.colors[other_color]
+            .iter()
+            .any(|&cell| self.sees(color, cell))
+    }
+}
+
+impl Sudoku {
+    /// Build all clusters of strong links for `num` that hold at least two links,
+    /// assigning alternating colors along the links.
+    fn find_color_clusters(&self, num: u8) -> Vec<ColorCluster> {
+        let links: Vec<((usize, usize), (usize, usize))> = self
+            .find_strong_links(num)
+            .into_iter()
+            .map(|(link, _)| ((link.base.row, link.base.col), (link.top.row, link.top.col)))
+            .collect();
+        let mut clusters: Vec<ColorCluster> = Vec::new();
+        for &(start, _) in &links {
+            if clusters.iter().any(|cluster| cluster.contains(&start)) {
+                continue;
+            }
+            let mut cluster = ColorCluster {
+                num,
+                colors: [vec![start], Vec::new()],
+            };
+            let mut queue = vec![(start, 0)];
+            while let Some((cell, color)) = queue.pop() {
+                for &(cell1, cell2) in &links {
+                    let next = if cell1 == cell {
+                        cell2
+                    } else if cell2 == cell {
+                        cell1
+                    } else {
+                        continue;
+                    };
+                    if !cluster.contains(&next) {
+                        cluster.colors[1 - color].push(next);
+                        queue.push((next, 1 - color));
+                    }
+                }
+            }
+            if cluster.cells().count() > 2 {
+                clusters.push(cluster);
+            }
+        }
+        clusters
+    }
+
+    /// Report the color classes of the clusters, numbering the colors consecutively
+    fn push_color_clusters(clusters: &[&ColorCluster], result: &mut RemovalResult) {
+        for (index, cluster) in clusters.iter().enumerate() {
+            for color in 0..2 {
+                for &(row, col) in &cluster.colors[color] {
+                    let candidate = Candidate {
+                        row,
+                        col,
+                        num: cluster.num,
+                    };
+                    result.candidates_affected.push(candidate);
+                    result
+                        .colored_candidates
+                        .push((candidate, 2 * index + color));
+                }
+            }
+        }
+    }
+
+    /// Find and resolve Simple Colors Traps.
+    /// A candidate outside a cluster that sees cells of both colors can be removed, since
+    /// one of the colors is true.
+    pub fn find_simple_colors_trap(&self) -> StrategyResult {
+        log::info!("Finding Simple Colors Trap");
+        let mut result = RemovalResult::empty();
+        for num in 1..=9 {
+            for cluster in self.find_color_clusters(num) {
+                for row in 0..9 {
+                    for col in 0..9 {
+                        if self.candidates[row][col].contains(num)
+                            && !cluster.contains(&(row, col))
+                            && cluster.sees(0, (row, col))
+                            && cluster.sees(1, (row, col))
+                        {
+                            result.candidates_about_to_be_removed.insert(Candidate {
+                                row,
+                                col,
+                                num,
+                            });
+                        }
+                    }
+                }
+                if result.will_remove_candidates() {
+                    Self::push_color_clusters(&[&cluster], &mut result);
+                    return StrategyResult {
+                        strategy: Strategy::SimpleColorsTrap,
+                        removals: result,
+                    };
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::SimpleColorsTrap,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve Simple Colors Wraps.
+    /// If two cells of the same color see each other, that color can't be true and the
+    /// digit is removed from all cells of that color.
+    pub fn find_simple_colors_wrap(&self) -> StrategyResult {
+        log::info!("Finding Simple Colors Wrap");
+        let mut result = RemovalResult::empty();
+        for num in 1..=9 {
+            for cluster in self.find_color_clusters(num) {
+                for color in 0..2 {
+                    if !cluster.sees_color(color, &cluster, color) {
+                        continue;
+                    }
+                    for &(row, col) in &cluster.colors[color] {
+                        result
+                            .candidates_about_to_be_removed
+                            .insert(Candidate { row, col, num });
+                    }
+                    Self::push_color_clusters(&[&cluster], &mut result);
+                    return StrategyResult {
+                        strategy: Strategy::SimpleColorsWrap,
+                        removals: result,
+                    };
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::SimpleColorsWrap,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve Multi Colors across two clusters of the same digit.
+    /// If a color of the first cluster sees both colors of the second cluster, it can't be
+    /// true and is removed (type 2). If a color of the first cluster sees one color of the
+    /// second, one of the two opposite colors is true, so the digit can be removed from all
+    /// cells that see both opposite colors (type 1).
+    pub fn find_multi_colors(&self) -> StrategyResult {
+        log::info!("Finding Multi Colors");
+        let mut result = RemovalResult::empty();
+        for num in 1..=9 {
+            let clusters = self.find_color_clusters(num);
+            for (i, cluster1) in clusters.iter().enumerate() {
+                for (j, cluster2) in clusters.iter().enumerate() {
+                    if i == j {
+                        continue;
+                    }
+                    for color1 in 0..2 {
+                        // Type 2: the color sees both colors of the other cluster
+                        if cluster1.sees_color(color1, cluster2, 0)
+                            && cluster1.sees_color(color1, cluster2, 1)
+                        {
+                            for &(row, col) in &cluster1.colors[color1] {
+                                result.candidates_about_to_be_removed.insert(Candidate {
+                                    row,
+                                    col,
+                                    num,
+                                });
+                            }
+                        }
+                        // Type 1: the colors can't both be true, so one of their opposites is
+                        for color2 in 0..2 {
+                            if i > j || !cluster1.sees_color(color1, cluster2, color2) {
+                                continue;
+                            }
+                            for row in 0..9 {
+                                for col in 0..9 {
+                                    if self.candidates[row][col].contains(num)
+                                        && !cluster1.contains(&(row, col))
+                                        && !cluster2.contains(&(row, col))
+                                        && cluster1.sees(1 - color1, (row, col))
+                                        && cluster2.sees(1 - color2, (row, col))
+                                    {
+                                        result.candidates_about_to_be_removed.insert(Candidate {
+                                            row,
+                                            col,
+                                            num,
+                                        });
+                                    }
+                                }
+                            }
+                        }
+                        if result.will_remove_candidates() {
+                            Self::push_color_clusters(&[cluster1, cluster2], &mut result);
+                            return StrategyResult {
+                                strategy: Strategy::MultiColors,
+                                removals: result,
+                            };
+                        }
+                    }
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::MultiColors,
+            removals: result,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{SolvingStrategy, Strategy, StrategyRegistry};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+/// Predefined solver configurations
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverProfile {
+    /// All techniques with their HoDoKu effort values
+    Hodoku,
+    /// All techniques, weighted and ordered like Sudoku Explainer rates them
+    SudokuExplainer,
+    /// A player who only knows singles and pairs
+    SinglesAndPairs,
+}
+
+impl Display for SolverProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolverProfile::Hodoku => write!(f, "hodoku"),
+            SolverProfile::SudokuExplainer => write!(f, "sudoku-explainer"),
+            SolverProfile::SinglesAndPairs => write!(f, "singles-and-pairs"),
+        }
+    }
+}
+
+/// Which techniques the human-like solver uses, in which order, and how much effort each
+/// of them takes. By default, all registered techniques are used, ordered by effort.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolverConfig {
+    /// If set, only these techniques are used
+    pub enabled: Option<HashSet<Strategy>>,
+    /// Techniques that are never used
+    pub disabled: HashSet<Strategy>,
+    /// Techniques that are tried first, in this order; all others follow ordered by effort
+    pub order: Vec<Strategy>,
+    /// Effort values replacing the ones of the registered techniques
+    pub efforts: HashMap<Strategy, i32>,
+    /// Effort added per removed candidate each time a chain exceeds the next length of the
+    /// series 4, 6, 8, 12, 16, 24, … nodes
+    pub chain_bonus_step: i32,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        SolverConfig {
+            enabled: None,
+            disabled: HashSet::new(),
+            order: Vec::new(),
+            efforts: HashMap::new(),
+            chain_bonus_step: 10,
+        }
+    }
+}
+
+impl SolverConfig {
+    pub fn new() -> Self {
+        SolverConfig::default()
+    }
+
+    /// Use the given techniques only
+    pub fn only(strategies: &[Strategy]) -> Self {
+        SolverConfig {
+            enabled: Some(strategies.iter().cloned().collect()),
+            ..SolverConfig::default()
+        }
+    }
+
+    /// Rate as if the player knows singles, locked candidates and pairs only. Puzzles that
+    /// need more than that can't be solved with this configuration.
+    pub fn singles_and_pairs() -> Self {
+        SolverConfig::only(&[
+            Strategy::LastDigit,
+            Strategy::ObviousSingle,
+            Strategy::HiddenSingle,
+            Strategy::LockedPair,
+            Strategy::PointingPair,
+            Strategy::ClaimingPair,
+            Strategy::ObviousPair,
+            Strategy::HiddenPair,
+        ])
+    }
+
+    /// Use Sudoku Explainer's ratings, multiplied by 10, as effort values. Techniques that
+    /// Sudoku Explainer doesn't know get the rating of the technique it would find instead,
+    /// e.g. an X-Cycle for a Skyscraper or a Nishio for Pattern Overlay. Long chains add 0.1
+    /// per length step, like they do in Sudoku Explainer.
+    pub fn sudoku_explainer() -> Self {
+        SolverConfig {
+            efforts: HashMap::from([
+                (Strategy::LastDigit, 10),
+                (Strategy::HiddenSingle, 15),
+                (Strategy::ObviousSingle, 23),
+                (Strategy::PointingPair, 26),
+                (Strategy::ClaimingPair, 28),
+                (Strategy::LockedPair, 30),
+                (Strategy::ObviousPair, 30),
+                (Strategy::XWing, 32),
+                (Strategy::HiddenPair, 34),
+                (Strategy::FinnedXWing, 34),
+                (Strategy::SashimiXWing, 34),
+                (Strategy::ObviousTriplet, 36),
+                (Strategy::Swordfish, 38),
+                (Strategy::HiddenTriplet, 40),
+                (Strategy::FinnedSwordfish, 40),
+                (Strategy::SashimiSwordfish, 40),
+                (Strategy::XYWing, 42),
+                (Strategy::XYZWing, 44),
+                (Strategy::WWing, 44),
+                (Strategy::UniqueRectangleType1, 45),
+                (Strategy::UniqueRectangleType2, 45),
+                (Strategy::UniqueRectangleType3, 45),
+                (Strategy::UniqueRectangleType4, 45),
+                (Strategy::UniqueRectangleType5, 45),
+                (Strategy::UniqueRectangleType6, 45),
+                (Strategy::HiddenUniqueRectangle, 48),
+                (Strategy::ObviousQuad, 50),
+                (Strategy::Jellyfish, 52),
+                (Strategy::FinnedJellyfish, 54),
+                (Strategy::SashimiJellyfish, 54),
+                (Strategy::HiddenQuad, 54),
+                (Strategy::BugPlusOne, 56),
+                (Strategy::Skyscraper, 65),
+                (Strategy::TwoStringKite, 65),
+                (Strategy::TurbotFish, 65),
+                (Strategy::EmptyRectangle, 65),
+                (Strategy::SimpleColorsTrap, 65),
+                (Strategy::SimpleColorsWrap, 65),
+                (Strategy::XChain, 66),
+                (Strategy::XYChain, 66),
+                (Strategy::ContinuousNiceLoop, 66),
+                (Strategy::DiscontinuousNiceLoop, 66),
+                (Strategy::MultiColors, 70),
+                (Strategy::Medusa3D, 70),
+                (Strategy::Aic, 70),
+                (Strategy::GroupedContinuousNiceLoop, 70),
+                (Strategy::GroupedDiscontinuousNiceLoop, 70),
+                (Strategy::GroupedAic, 70),
+                (Strategy::AlsXz, 75),
+                (Strategy::AlsXyWing, 75),
+                (Strategy::SueDeCoq, 75),
+                (Strategy::PatternOverlay, 75),
+                (Strategy::DeathBlossom, 80),
+                (Strategy::CellForcingChain, 83),
+                (Strategy::UnitForcingChain, 83),
+                (Strategy::ContradictionForcingNet, 85),
+                (Strategy::BowmansBingo, 95),
+            ]),
+            chain_bonus_step: 1,
+            ..SolverConfig::default()
+        }
+    }
+
+    /// Whether the human-like solver may use the technique
+    pub fn is_enabled(&self, strategy: Strategy) -> bool {
+        !self.disabled.contains(&strategy)
+            && self
+                .enabled
+                .as_ref()
+                .is_none_or(|enabled| enabled.contains(&strategy))
+    }
+
+    /// Effort of removing one candidate with the technique; `None` for custom techniques
+    /// that are neither configured nor registered
+    pub fn effort(&self, strategy: Strategy, registry: &StrategyRegistry) -> Option<i32> {
+        self.efforts
+            .get(&strategy)
+            .cloned()
+            .or_else(|| registry.effort(strategy))
+    }
+
+    /// The enabled techniques of the registry in the order they are tried
+    pub fn strategies<'a>(&self, registry: &'a StrategyRegistry) -> Vec<&'a dyn SolvingStrategy> {
+        let mut strategies: Vec<&dyn SolvingStrategy> = registry
+            .iter()
+            .filter(|solving_strategy| self.is_enabled(solving_strategy.strategy()))
+            .collect();
+        strategies.sort_by_key(|solving_strategy| {
+            let strategy = solving_strategy.strategy();
+            match self.order.iter().position(|&other| other == strategy) {
+                Some(position) => (0, position as i32),
+                None => (
+                    1,
+                    self.efforts
+                        .get(&strategy)
+                        .cloned()
+                        .unwrap_or_else(|| solving_strategy.effort()),
+                ),
+            }
+        });
+        strategies
+    }
+}
+
+impl From<SolverProfile> for SolverConfig {
+    fn from(profile: SolverProfile) -> Self {
+        match profile {
+            SolverProfile::Hodoku => SolverConfig::default(),
+            SolverProfile::SudokuExplainer => SolverConfig::sudoku_explainer(),
+            SolverProfile::SinglesAndPairs => SolverConfig::singles_and_pairs(),
+        }
+    }
+}
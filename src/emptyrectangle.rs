@@ -0,0 +1,95 @@
+use crate::{Candidate, RemovalResult, Strategy, StrategyResult, Sudoku, Unit};
+
+impl Sudoku {
+    /// Find the Empty Rectangle lines of a box: a row and a column through the box that
+    /// together hold all candidates for `num` in the box, with candidates on both lines
+    /// outside their crossing cell.
+    fn find_empty_rectangle_lines(&self, box_idx: usize, num: u8) -> Vec<(usize, usize)> {
+        let (start_row, start_col) = Self::get_box_start_from_index(box_idx);
+        let cells = self.find_cells_with_candidate_in_box(box_idx, num);
+        let mut lines = Vec::new();
+        for row in start_row..start_row + 3 {
+            for col in start_col..start_col + 3 {
+                if cells.iter().any(|&(r, c)| r != row && c != col) {
+                    continue;
+                }
+                if cells.iter().any(|&(r, c)| r == row && c != col)
+                    && cells.iter().any(|&(r, c)| c == col && r != row)
+                {
+                    lines.push((row, col));
+                }
+            }
+        }
+        lines
+    }
+
+    /// Find and resolve Empty Rectangles.
+    /// All candidates for a digit in a box lie on one row and one column of the box. Together
+    /// with a strong link outside the box that has one end on the rectangle's row (or column),
+    /// the digit can be removed where the other end's row (or column) crosses the rectangle's
+    /// column (or row): if it were true there, the box would have no place left for the digit.
+    pub fn find_empty_rectangle(&self) -> StrategyResult {
+        log::info!("Finding Empty Rectangle");
+        let mut result = RemovalResult::empty();
+        for box_idx in 0..9 {
+            let (start_row, start_col) = Self::get_box_start_from_index(box_idx);
+            let in_box_rows = |row: usize| (start_row..start_row + 3).contains(&row);
+            let in_box_cols = |col: usize| (start_col..start_col + 3).contains(&col);
+            for num in 1..=9 {
+                let lines = self.find_empty_rectangle_lines(box_idx, num);
+                if lines.is_empty() {
+                    continue;
+                }
+                for (link, unit) in self.find_strong_links(num) {
+                    for (er_row, er_col) in &lines {
+                        for (near, far) in [(link.base, link.top), (link.top, link.base)] {
+                            // The link's far end decides which cell on the other line is removed
+                            let target = match unit {
+                                Unit::Column
+                                    if !in_box_cols(near.col)
+                                        && near.row == *er_row
+                                        && !in_box_rows(far.row) =>
+                                {
+                                    (far.row, *er_col)
+                                }
+                                Unit::Row
+                                    if !in_box_rows(near.row)
+                                        && near.col == *er_col
+                                        && !in_box_cols(far.col) =>
+                                {
+                                    (*er_row, far.col)
+                                }
+                                _ => continue,
+                            };
+                            if !self.candidates[target.0][target.1].contains(num) {
+                                continue;
+                            }
+                            result.candidates_about_to_be_removed.insert(Candidate {
+                                row: target.0,
+                                col: target.1,
+                                num,
+                            });
+                            result.candidates_affected.extend(
+                                self.find_cells_with_candidate_in_box(box_idx, num)
+                                    .into_iter()
+                                    .map(|(row, col)| Candidate { row, col, num }),
+                            );
+                            result.candidates_affected.push(near);
+                            result.candidates_affected.push(far);
+                            result.unit = Some(Unit::Box);
+                            result.unit_index = Some(vec![box_idx]);
+                            return StrategyResult {
+                                strategy: Strategy::EmptyRectangle,
+                                removals: result,
+                            };
+                        }
+                    }
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::EmptyRectangle,
+            removals: result,
+        }
+    }
+}
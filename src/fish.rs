@@ -0,0 +1,276 @@
+use crate::{Candidate, RemovalResult, Strategy, StrategyResult, Sudoku, Unit};
+use std::collections::BTreeSet;
+
+impl Sudoku {
+    /// Map a position within a row or column to the coordinates of the cell.
+    pub(crate) fn get_line_cell(unit: &Unit, line: usize, pos: usize) -> (usize, usize) {
+        match unit {
+            Unit::Row => (line, pos),
+            _ => (pos, line),
+        }
+    }
+
+    /// Find the positions of candidate `num` in the row or column `line`.
+    pub(crate) fn find_positions_in_line(&self, unit: &Unit, line: usize, num: u8) -> Vec<usize> {
+        (0..9)
+            .filter(|&pos| {
+                let (row, col) = Self::get_line_cell(unit, line, pos);
+                self.candidates[row][col].contains(num)
+            })
+            .collect()
+    }
+
+    /// Find a basic fish of the given `size` with its base lines in `base_unit` (rows or columns).
+    /// A fish occurs when a digit is confined to `size` base lines and within these lines
+    /// only appears in `size` cover lines. The digit can then be removed from all other
+    /// cells of the cover lines. Size 2 is an X-Wing, 3 a Swordfish and 4 a Jellyfish.
+    pub fn find_basic_fish(&self, size: usize, base_unit: Unit) -> RemovalResult {
+        let mut result = RemovalResult::empty();
+        let cover_unit = match base_unit {
+            Unit::Row => Unit::Column,
+            _ => Unit::Row,
+        };
+        for num in 1..=9 {
+            // Only lines with two up to `size` candidates can be base lines
+            let lines: Vec<(usize, Vec<usize>)> = (0..9)
+                .map(|line| (line, self.find_positions_in_line(&base_unit, line, num)))
+                .filter(|(_, positions)| (2..=size).contains(&positions.len()))
+                .collect();
+            if lines.len() < size {
+                continue;
+            }
+            let line_indices: Vec<usize> = (0..lines.len()).collect();
+            for combination in Self::combinations(&line_indices, size) {
+                let base_lines: Vec<usize> = combination.iter().map(|&i| lines[i].0).collect();
+                let cover_lines: BTreeSet<usize> = combination
+                    .iter()
+                    .flat_map(|&i| lines[i].1.iter().cloned())
+                    .collect();
+                if cover_lines.len() != size {
+                    continue;
+                }
+                // Remove the digit from the cover lines outside the base lines
+                for &cover in &cover_lines {
+                    for line in 0..9 {
+                        if base_lines.contains(&line) {
+                            continue;
+                        }
+                        let (row, col) = Self::get_line_cell(&base_unit, line, cover);
+                        if self.candidates[row][col].contains(num) {
+                            result.candidates_about_to_be_removed.insert(Candidate {
+                                row,
+                                col,
+                                num,
+                            });
+                        }
+                    }
+                }
+                if result.will_remove_candidates() {
+                    for &i in &combination {
+                        let (line, positions) = &lines[i];
+                        for &pos in positions {
+                            let (row, col) = Self::get_line_cell(&base_unit, *line, pos);
+                            result.candidates_affected.push(Candidate { row, col, num });
+                        }
+                    }
+                    result.unit = Some(base_unit);
+                    result.unit_index = Some(base_lines);
+                    result.cover_unit = Some(cover_unit);
+                    result.cover_unit_index = Some(cover_lines.into_iter().collect());
+                    return result;
+                }
+            }
+        }
+        result
+    }
+
+    /// Find a basic fish of the given `size`, first with rows, then with columns as base lines.
+    pub(crate) fn find_fish(&self, size: usize, strategy: Strategy) -> StrategyResult {
+        log::info!("Finding {} in rows", strategy);
+        let result = self.find_basic_fish(size, Unit::Row);
+        if result.will_remove_candidates() {
+            return StrategyResult {
+                strategy,
+                removals: result,
+            };
+        }
+        log::info!("Finding {} in columns", strategy);
+        let result = self.find_basic_fish(size, Unit::Column);
+        StrategyResult {
+            strategy,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve Swordfish candidates (a fish with three base and cover lines).
+    pub fn find_swordfish(&self) -> StrategyResult {
+        self.find_fish(3, Strategy::Swordfish)
+    }
+
+    /// Find and resolve Jellyfish candidates (a fish with four base and cover lines).
+    pub fn find_jellyfish(&self) -> StrategyResult {
+        self.find_fish(4, Strategy::Jellyfish)
+    }
+
+    /// Find a finned or sashimi fish of the given `size` with its base lines in `base_unit`.
+    /// A finned fish is a basic fish with extra candidates (fins) in its base lines that
+    /// all lie in the same box. Either one of the fins is true, or the basic fish holds;
+    /// so the digit can only be removed from cover cells that also see every fin, i.e.
+    /// cells in the fins' box. A sashimi fish is a finned fish in which a base line has
+    /// at most one candidate left once the fins are taken away.
+    pub fn find_finned_fish(&self, size: usize, base_unit: Unit, sashimi: bool) -> RemovalResult {
+        let mut result = RemovalResult::empty();
+        let cover_unit = match base_unit {
+            Unit::Row => Unit::Column,
+            _ => Unit::Row,
+        };
+        for num in 1..=9 {
+            // Each base line holds up to `size` cover candidates plus up to three fins in a box
+            let lines: Vec<(usize, Vec<usize>)> = (0..9)
+                .map(|line| (line, self.find_positions_in_line(&base_unit, line, num)))
+                .filter(|(_, positions)| (2..=size + 3).contains(&positions.len()))
+                .collect();
+            if lines.len() < size {
+                continue;
+            }
+            let line_indices: Vec<usize> = (0..lines.len()).collect();
+            for combination in Self::combinations(&line_indices, size) {
+                let base_lines: Vec<usize> = combination.iter().map(|&i| lines[i].0).collect();
+                let all_positions: BTreeSet<usize> = combination
+                    .iter()
+                    .flat_map(|&i| lines[i].1.iter().cloned())
+                    .collect();
+                // Without fins it's a basic fish, and fins in one box span at most three positions
+                if all_positions.len() <= size || all_positions.len() > size + 3 {
+                    continue;
+                }
+                let all_positions: Vec<usize> = all_positions.into_iter().collect();
+                for cover_lines in Self::combinations(&all_positions, size) {
+                    let mut fins: Vec<Candidate> = Vec::new();
+                    let mut is_sashimi = false;
+                    let mut valid = true;
+                    for &i in &combination {
+                        let (line, positions) = &lines[i];
+                        let in_cover = positions.iter().filter(|p| cover_lines.contains(p)).count();
+                        if in_cover == 0 {
+                            valid = false;
+                            break;
+                        }
+                        if in_cover == 1 {
+                            is_sashimi = true;
+                        }
+                        for &pos in positions.iter().filter(|p| !cover_lines.contains(p)) {
+                            let (row, col) = Self::get_line_cell(&base_unit, *line, pos);
+                            fins.push(Candidate { row, col, num });
+                        }
+                    }
+                    if !valid || fins.is_empty() || is_sashimi != sashimi {
+                        continue;
+                    }
+                    // All fins have to be in the same box
+                    let fin_box = Self::get_box_start(fins[0].row, fins[0].col);
+                    if fins
+                        .iter()
+                        .any(|fin| Self::get_box_start(fin.row, fin.col) != fin_box)
+                    {
+                        continue;
+                    }
+                    // Every cover line needs a candidate in the base lines
+                    if cover_lines
+                        .iter()
+                        .any(|&cover| !combination.iter().any(|&i| lines[i].1.contains(&cover)))
+                    {
+                        continue;
+                    }
+                    // Remove the digit from cover cells outside the base lines that see all fins
+                    for &cover in &cover_lines {
+                        for line in 0..9 {
+                            if base_lines.contains(&line) {
+                                continue;
+                            }
+                            let (row, col) = Self::get_line_cell(&base_unit, line, cover);
+                            if Self::get_box_start(row, col) == fin_box
+                                && self.candidates[row][col].contains(num)
+                            {
+                                result.candidates_about_to_be_removed.insert(Candidate {
+                                    row,
+                                    col,
+                                    num,
+                                });
+                            }
+                        }
+                    }
+                    if result.will_remove_candidates() {
+                        for &i in &combination {
+                            let (line, positions) = &lines[i];
+                            for &pos in positions {
+                                let (row, col) = Self::get_line_cell(&base_unit, *line, pos);
+                                result.candidates_affected.push(Candidate { row, col, num });
+                            }
+                        }
+                        result.unit = Some(base_unit);
+                        result.unit_index = Some(base_lines);
+                        result.cover_unit = Some(cover_unit);
+                        result.cover_unit_index = Some(cover_lines);
+                        result.fins = fins;
+                        return result;
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Find a finned or sashimi fish of the given `size`, first with rows, then with columns as base lines.
+    fn find_finned_fish_in_units(
+        &self,
+        size: usize,
+        sashimi: bool,
+        strategy: Strategy,
+    ) -> StrategyResult {
+        log::info!("Finding {} in rows", strategy);
+        let result = self.find_finned_fish(size, Unit::Row, sashimi);
+        if result.will_remove_candidates() {
+            return StrategyResult {
+                strategy,
+                removals: result,
+            };
+        }
+        log::info!("Finding {} in columns", strategy);
+        let result = self.find_finned_fish(size, Unit::Column, sashimi);
+        StrategyResult {
+            strategy,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve Finned X-Wing candidates.
+    pub fn find_finned_xwing(&self) -> StrategyResult {
+        self.find_finned_fish_in_units(2, false, Strategy::FinnedXWing)
+    }
+
+    /// Find and resolve Finned Swordfish candidates.
+    pub fn find_finned_swordfish(&self) -> StrategyResult {
+        self.find_finned_fish_in_units(3, false, Strategy::FinnedSwordfish)
+    }
+
+    /// Find and resolve Finned Jellyfish candidates.
+    pub fn find_finned_jellyfish(&self) -> StrategyResult {
+        self.find_finned_fish_in_units(4, false, Strategy::FinnedJellyfish)
+    }
+
+    /// Find and resolve Sashimi X-Wing candidates.
+    pub fn find_sashimi_xwing(&self) -> StrategyResult {
+        self.find_finned_fish_in_units(2, true, Strategy::SashimiXWing)
+    }
+
+    /// Find and resolve Sashimi Swordfish candidates.
+    pub fn find_sashimi_swordfish(&self) -> StrategyResult {
+        self.find_finned_fish_in_units(3, true, Strategy::SashimiSwordfish)
+    }
+
+    /// Find and resolve Sashimi Jellyfish candidates.
+    pub fn find_sashimi_jellyfish(&self) -> StrategyResult {
+        self.find_finned_fish_in_units(4, true, Strategy::SashimiJellyfish)
+    }
+}
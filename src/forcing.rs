@@ -0,0 +1,264 @@
+use crate::{Candidate, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, Unit};
+use std::collections::{HashMap, VecDeque};
+
+/// The values that follow from assuming a candidate to be true or false
+struct Implications {
+    values: HashMap<Candidate, bool>,
+    /// Whether the assumption led to a candidate being both true and false, a cell without
+    /// candidates, or a digit without a place in a unit
+    contradiction: bool,
+}
+
+impl Implications {
+    fn value(&self, candidate: &Candidate) -> Option<bool> {
+        self.values.get(candidate).cloned()
+    }
+}
+
+impl Sudoku {
+    /// The units containing the cell, with their indices
+    fn units_of_cell(row: usize, col: usize) -> [(Unit, usize); 3] {
+        [
+            (Unit::Row, row),
+            (Unit::Column, col),
+            (Unit::Box, Self::get_box_index(row, col)),
+        ]
+    }
+
+    /// Candidates that can't be true together with `candidate`: the other candidates of its
+    /// cell and the same digit in all cells it sees
+    fn weakly_linked_candidates(&self, candidate: &Candidate) -> Vec<Candidate> {
+        let mut linked = Vec::new();
+        for row in 0..9 {
+            for col in 0..9 {
+                for num in self.candidates[row][col] {
+                    let same_cell = (row, col) == (candidate.row, candidate.col);
+                    if (same_cell && num != candidate.num)
+                        || (num == candidate.num
+                            && Self::cells_see_each_other(
+                                (row, col),
+                                (candidate.row, candidate.col),
+                            ))
+                    {
+                        linked.push(Candidate { row, col, num });
+                    }
+                }
+            }
+        }
+        linked
+    }
+
+    /// Candidates that become true when `candidate` is false. A forcing chain only follows
+    /// strong links (bivalue cells and conjugate pairs), a forcing net also takes the values
+    /// found so far into account: the last remaining candidate of a cell or the last place of
+    /// a digit in a unit. Reports a contradiction if nothing is left.
+    fn forced_candidates(
+        &self,
+        candidate: &Candidate,
+        values: &HashMap<Candidate, bool>,
+        net: bool,
+    ) -> Result<Vec<Candidate>, ()> {
+        let is_open = |c: &Candidate| !net || values.get(c) != Some(&false);
+        let mut forced = Vec::new();
+        let cell: Vec<Candidate> = self.candidates[candidate.row][candidate.col]
+            .iter()
+            .map(|num| Candidate {
+                row: candidate.row,
+                col: candidate.col,
+                num,
+            })
+            .filter(|c| c != candidate && is_open(c))
+            .collect();
+        match cell.len() {
+            0 if net => return Err(()),
+            1 => forced.push(cell[0]),
+            _ => {}
+        }
+        for (unit, index) in Self::units_of_cell(candidate.row, candidate.col) {
+            let places: Vec<Candidate> = Self::get_unit_cells(&unit, index)
+                .into_iter()
+                .filter(|&(row, col)| self.candidates[row][col].contains(candidate.num))
+                .map(|(row, col)| Candidate {
+                    row,
+                    col,
+                    num: candidate.num,
+                })
+                .filter(|c| c != candidate && is_open(c))
+                .collect();
+            match places.len() {
+                0 if net => return Err(()),
+                1 => forced.push(places[0]),
+                _ => {}
+            }
+        }
+        Ok(forced)
+    }
+
+    /// Follow all implications of assuming `start` to be `value`
+    fn propagate_assumption(&self, start: Candidate, value: bool, net: bool) -> Implications {
+        let mut values: HashMap<Candidate, bool> = HashMap::new();
+        let mut queue: VecDeque<(Candidate, bool)> = VecDeque::new();
+        values.insert(start, value);
+        queue.push_back((start, value));
+        while let Some((candidate, value)) = queue.pop_front() {
+            let implied: Vec<(Candidate, bool)> = if value {
+                self.weakly_linked_candidates(&candidate)
+                    .into_iter()
+                    .map(|c| (c, false))
+                    .collect()
+            } else {
+                match self.forced_candidates(&candidate, &values, net) {
+                    Ok(forced) => forced.into_iter().map(|c| (c, true)).collect(),
+                    Err(()) => {
+                        return Implications {
+                            values,
+                            contradiction: true,
+                        };
+                    }
+                }
+            };
+            for (c, v) in implied {
+                match values.get(&c) {
+                    Some(&known) if known != v => {
+                        return Implications {
+                            values,
+                            contradiction: true,
+                        };
+                    }
+                    Some(_) => {}
+                    None => {
+                        values.insert(c, v);
+                        queue.push_back((c, v));
+                    }
+                }
+            }
+        }
+        Implications {
+            values,
+            contradiction: false,
+        }
+    }
+
+    /// Collect the conclusions common to all branches: if every possible branch sets a
+    /// candidate, that cell is solved; candidates false in every branch are removed.
+    /// Branches that lead to a contradiction can't be true and are ignored.
+    fn common_implications(&self, branches: &[Candidate]) -> RemovalResult {
+        let implications: Vec<Implications> = branches
+            .iter()
+            .map(|&branch| self.propagate_assumption(branch, true, false))
+            .filter(|implications| !implications.contradiction)
+            .collect();
+        let mut result = RemovalResult::empty();
+        if implications.is_empty() {
+            return result;
+        }
+        let mut candidates: Vec<(&Candidate, &bool)> = implications[0].values.iter().collect();
+        candidates.sort_by_key(|(c, _)| (c.row, c.col, c.num));
+        for (candidate, &value) in candidates {
+            if branches.contains(candidate)
+                || implications[1..]
+                    .iter()
+                    .any(|other| other.value(candidate) != Some(value))
+            {
+                continue;
+            }
+            if value {
+                result = self.collect_set_num(candidate.num, candidate.row, candidate.col);
+                break;
+            }
+            result.candidates_about_to_be_removed.insert(*candidate);
+        }
+        if result.will_remove_candidates() {
+            result.candidates_affected = branches.to_vec();
+        }
+        result
+    }
+
+    /// Find and resolve Cell Forcing Chains.
+    /// Assume each candidate of a cell in turn and follow the forcing chains from it. One of
+    /// them is true, so whatever follows from all of them is true as well.
+    pub fn find_cell_forcing_chain(&self) -> StrategyResult {
+        log::info!("Finding Cell Forcing Chain");
+        for count in 2..=9 {
+            for (row, col) in self.cells_with_candidate_count(count) {
+                let nums: Vec<u8> = self.candidates[row][col].iter().collect();
+                let branches: Vec<Candidate> = nums
+                    .into_iter()
+                    .map(|num| Candidate { row, col, num })
+                    .collect();
+                let result = self.common_implications(&branches);
+                if result.will_remove_candidates() {
+                    return StrategyResult {
+                        strategy: Strategy::CellForcingChain,
+                        removals: result,
+                    };
+                }
+            }
+        }
+        StrategyResult::new(Strategy::CellForcingChain)
+    }
+
+    /// Find and resolve Unit Forcing Chains.
+    /// Assume each place of a digit in a unit in turn and follow the forcing chains from it.
+    /// One of them is true, so whatever follows from all of them is true as well.
+    pub fn find_unit_forcing_chain(&self) -> StrategyResult {
+        log::info!("Finding Unit Forcing Chain");
+        for unit in [Unit::Row, Unit::Column, Unit::Box] {
+            for index in 0..9 {
+                for num in 1..=9 {
+                    let branches: Vec<Candidate> = Self::get_unit_cells(&unit, index)
+                        .into_iter()
+                        .filter(|&(row, col)| {
+                            self.board[row][col] == EMPTY && self.candidates[row][col].contains(num)
+                        })
+                        .map(|(row, col)| Candidate { row, col, num })
+                        .collect();
+                    if branches.len() < 2 {
+                        continue;
+                    }
+                    let mut result = self.common_implications(&branches);
+                    if result.will_remove_candidates() {
+                        result.unit = Some(unit);
+                        result.unit_index = Some(vec![index]);
+                        return StrategyResult {
+                            strategy: Strategy::UnitForcingChain,
+                            removals: result,
+                        };
+                    }
+                }
+            }
+        }
+        StrategyResult::new(Strategy::UnitForcingChain)
+    }
+
+    /// Find and resolve Contradiction Forcing Nets.
+    /// Assume a candidate to be true and follow all implications, including cells and units
+    /// that run out of alternatives on the way. If that leads to a contradiction, the
+    /// candidate is false.
+    pub fn find_contradiction_forcing_net(&self) -> StrategyResult {
+        log::info!("Finding Contradiction Forcing Net");
+        let mut result = RemovalResult::empty();
+        for count in 2..=9 {
+            for (row, col) in self.cells_with_candidate_count(count) {
+                for num in self.candidates[row][col] {
+                    let candidate = Candidate { row, col, num };
+                    if self
+                        .propagate_assumption(candidate, true, true)
+                        .contradiction
+                    {
+                        result.candidates_about_to_be_removed.insert(candidate);
+                        result.candidates_affected.push(candidate);
+                        return StrategyResult {
+                            strategy: Strategy::ContradictionForcingNet,
+                            removals: result,
+                        };
+                    }
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::ContradictionForcingNet,
+            removals: result,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use clap::Parser;
+use rate_my_sudoku::SolverProfile;
 use rate_my_sudoku::generator::{FillAlgorithm, SudokuGenerator, ThinningAlgorithm};
 use std::io::Write;
 use std::sync::mpsc;
@@ -23,6 +24,10 @@ struct Cli {
     min_effort: Option<f64>,
     #[arg(long, help = "Maximum effort for the Sudoku puzzle")]
     max_effort: Option<f64>,
+    #[arg(long, default_value_t = SolverProfile::Hodoku, help = "Solver profile used to rate the Sudoku puzzles")]
+    profile: SolverProfile,
+    #[arg(long, help = "Append the solve path as JSON to each Sudoku puzzle")]
+    path: bool,
     #[arg(short = 't', long, help = "Number of threads to use for generation")]
     num_threads: Option<usize>,
     #[arg(long, help = "Mask to use for the Sudoku puzzle")]
@@ -53,6 +58,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let max_filled_cells = cli.max_filled_cells;
     let fill_algorithm = cli.algorithm;
     let thinning = cli.thinning;
+    let profile = cli.profile;
+    let with_path = cli.path;
     let thread_count = match cli.num_threads {
         Some(num_threads) => num_threads,
         None => num_cpus::get(),
@@ -79,13 +86,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     let sudoku_string = sudoku.to_board_string();
                     let mut computer_sudoku = sudoku.clone();
                     let mut sudoku = sudoku;
-                    if sudoku.solve_human_like() {
+                    sudoku.config = profile.into();
+                    let path = sudoku.solve_human_like();
+                    let output = if with_path {
+                        format!("{} {}", sudoku_string, path.to_json())
+                    } else {
+                        sudoku_string.clone()
+                    };
+                    if path.solved {
                         computer_sudoku.solve_by_backtracking();
                         if sudoku == computer_sudoku {
-                            if min_effort.unwrap_or(f64::NEG_INFINITY) < sudoku.effort()
-                                && sudoku.effort() < max_effort.unwrap_or(f64::INFINITY)
+                            let effort = sudoku.rating_report().effort();
+                            if min_effort.unwrap_or(f64::NEG_INFINITY) < effort
+                                && effort < max_effort.unwrap_or(f64::INFINITY)
                             {
-                                tx.send((sudoku.effort(), sudoku_string)).unwrap();
+                                tx.send((effort, output)).unwrap();
                             }
                         } else {
                             log::error!(
@@ -96,7 +111,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             );
                         }
                     } else if max_effort.is_none() {
-                        tx.send((f64::INFINITY, sudoku_string)).unwrap();
+                        tx.send((f64::INFINITY, output)).unwrap();
                     }
                 }
             }
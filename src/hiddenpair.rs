@@ -12,7 +12,7 @@ impl Sudoku {
                 if self.board[row][col] != EMPTY {
                     continue;
                 }
-                for &num in &self.candidates[row][col] {
+                for num in self.candidates[row][col] {
                     digit_locations.entry(num).or_default().push(col);
                 }
             }
@@ -37,9 +37,7 @@ impl Sudoku {
                 // Remove all other digits from these two cells
                 for &col in &[col1, col2] {
                     for num in 1..=9 {
-                        if num != digit1
-                            && num != digit2
-                            && self.candidates[row][col].contains(&num)
+                        if num != digit1 && num != digit2 && self.candidates[row][col].contains(num)
                         {
                             result.candidates_about_to_be_removed.insert(Candidate {
                                 row,
@@ -92,7 +90,7 @@ impl Sudoku {
                 if self.board[row][col] != EMPTY {
                     continue;
                 }
-                for &num in &self.candidates[row][col] {
+                for num in self.candidates[row][col] {
                     digit_locations.entry(num).or_default().push(row);
                 }
             }
@@ -118,9 +116,7 @@ impl Sudoku {
                 // Remove all other digits from these two cells
                 for &row in &[row1, row2] {
                     for num in 1..=9 {
-                        if num != digit1
-                            && num != digit2
-                            && self.candidates[row][col].contains(&num)
+                        if num != digit1 && num != digit2 && self.candidates[row][col].contains(num)
                         {
                             result.candidates_about_to_be_removed.insert(Candidate {
                                 row,
@@ -180,7 +176,7 @@ impl Sudoku {
                         if self.board[row][col] != EMPTY {
                             continue;
                         }
-                        for &num in &self.candidates[row][col] {
+                        for num in self.candidates[row][col] {
                             digit_locations.entry(num).or_default().push((row, col));
                         }
                     }
@@ -210,7 +206,7 @@ impl Sudoku {
                         for num in 1..=9 {
                             if num != digit1
                                 && num != digit2
-                                && self.candidates[row][col].contains(&num)
+                                && self.candidates[row][col].contains(num)
                             {
                                 result.candidates_about_to_be_removed.insert(Candidate {
                                     row,
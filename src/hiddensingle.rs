@@ -34,10 +34,10 @@ impl Sudoku {
                 if self.board[row][col] > 0 {
                     continue;
                 }
-                for &num in &self.candidates[row][col] {
+                for num in self.candidates[row][col] {
                     let mut found = false;
                     for i in 0..9 {
-                        if i != col && self.candidates[row][i].contains(&num) {
+                        if i != col && self.candidates[row][i].contains(num) {
                             found = true;
                             break;
                         }
@@ -61,10 +61,10 @@ impl Sudoku {
                 if self.board[row][col] != EMPTY {
                     continue;
                 }
-                for &num in &self.candidates[row][col] {
+                for num in self.candidates[row][col] {
                     let mut found = false;
                     for i in 0..9 {
-                        if i != row && self.candidates[i][col].contains(&num) {
+                        if i != row && self.candidates[i][col].contains(num) {
                             found = true;
                             break;
                         }
@@ -95,14 +95,14 @@ impl Sudoku {
                         if self.board[row][col] != EMPTY {
                             continue;
                         }
-                        for &num in &self.candidates[row][col] {
+                        for num in self.candidates[row][col] {
                             let mut found = false;
                             'box_check: for r in 0..3 {
                                 for c in 0..3 {
                                     let check_row = start_row + r;
                                     let check_col = start_col + c;
                                     if (check_row != row || check_col != col)
-                                        && self.candidates[check_row][check_col].contains(&num)
+                                        && self.candidates[check_row][check_col].contains(num)
                                     {
                                         found = true;
                                         break 'box_check;
@@ -1,5 +1,4 @@
 use crate::{ALL_DIGITS, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, Unit};
-use std::collections::HashSet;
 
 impl Sudoku {
     /// Check if there are last digits in any of the rows.
@@ -14,12 +13,9 @@ impl Sudoku {
             if empty_cells.len() != 1 {
                 continue;
             }
-            let missing_digits: HashSet<u8> = ALL_DIGITS
-                .difference(&self.collect_nums_in_row(row))
-                .cloned()
-                .collect();
+            let missing_digits = ALL_DIGITS.difference(&self.collect_nums_in_row(row));
             assert_eq!(missing_digits.len(), 1);
-            let num = *missing_digits.iter().next().unwrap();
+            let num = missing_digits.first().unwrap();
             let col = empty_cells[0];
             let mut result = self.collect_set_num(num, row, col);
             result.unit = Some(Unit::Row);
@@ -38,12 +34,9 @@ impl Sudoku {
                 continue;
             }
             let row = empty_cells[0];
-            let missing_digits: HashSet<u8> = ALL_DIGITS
-                .difference(&self.collect_nums_in_col(col))
-                .cloned()
-                .collect();
+            let missing_digits = ALL_DIGITS.difference(&self.collect_nums_in_col(col));
             assert_eq!(missing_digits.len(), 1);
-            let num = *missing_digits.iter().next().unwrap();
+            let num = missing_digits.first().unwrap();
             let mut result = self.collect_set_num(num, row, col);
             result.unit = Some(Unit::Column);
             result.unit_index = Some(vec![col]);
@@ -75,14 +68,11 @@ impl Sudoku {
             if count != 1 {
                 continue;
             }
-            let missing_digits: HashSet<u8> = ALL_DIGITS
-                .difference(&self.collect_nums_in_box(box_index))
-                .cloned()
-                .collect();
+            let missing_digits = ALL_DIGITS.difference(&self.collect_nums_in_box(box_index));
             if missing_digits.len() != 1 {
                 continue;
             }
-            let num = *missing_digits.iter().next().unwrap();
+            let num = missing_digits.first().unwrap();
             let mut result = self.collect_set_num(num, empty_row, empty_col);
             result.unit = Some(Unit::Box);
             result.unit_index = Some(vec![box_index]);
@@ -1,19 +1,48 @@
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
-use std::sync::LazyLock;
-
+use std::sync::Arc;
+
+mod aic;
+mod als;
+mod bingo;
+mod bug;
+mod candidateset;
+mod chains;
 mod claimingpair;
+mod coloring;
+mod config;
+mod emptyrectangle;
+mod fish;
+mod forcing;
 pub mod generator;
 mod hiddenpair;
 mod hiddensingle;
 mod lastdigit;
+mod lockedcandidates;
+mod medusa;
 mod obviouspair;
 mod obvioussingle;
+mod patternoverlay;
 mod pointingpair;
+mod quads;
+mod rating;
+mod registry;
 mod skyscraper;
+mod solvepath;
+mod suedecoq;
 mod triplets;
+mod turbotfish;
+mod uniquerectangle;
+mod wwing;
 mod xwing;
+mod xywing;
+
+pub use candidateset::{CandidateSet, CandidateSetIter};
+pub use config::{SolverConfig, SolverProfile};
+pub use rating::{RatingReport, RatingReportEntry, StrategyRating};
+pub use registry::{SolvingStrategy, StrategyRegistry};
+pub use solvepath::{SolvePath, SolveStep};
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum Unit {
@@ -44,8 +73,56 @@ pub enum Strategy {
     PointingPair,
     ClaimingPair,
     ObviousTriplet,
+    HiddenTriplet,
+    ObviousQuad,
+    HiddenQuad,
     Skyscraper,
     XWing,
+    Swordfish,
+    Jellyfish,
+    FinnedXWing,
+    FinnedSwordfish,
+    FinnedJellyfish,
+    SashimiXWing,
+    SashimiSwordfish,
+    SashimiJellyfish,
+    XYWing,
+    XYZWing,
+    WWing,
+    TurbotFish,
+    TwoStringKite,
+    EmptyRectangle,
+    UniqueRectangleType1,
+    UniqueRectangleType2,
+    UniqueRectangleType3,
+    UniqueRectangleType4,
+    UniqueRectangleType5,
+    UniqueRectangleType6,
+    HiddenUniqueRectangle,
+    BugPlusOne,
+    SimpleColorsTrap,
+    SimpleColorsWrap,
+    MultiColors,
+    Medusa3D,
+    XChain,
+    XYChain,
+    ContinuousNiceLoop,
+    DiscontinuousNiceLoop,
+    Aic,
+    GroupedContinuousNiceLoop,
+    GroupedDiscontinuousNiceLoop,
+    GroupedAic,
+    AlsXz,
+    AlsXyWing,
+    DeathBlossom,
+    SueDeCoq,
+    CellForcingChain,
+    UnitForcingChain,
+    ContradictionForcingNet,
+    PatternOverlay,
+    BowmansBingo,
+    /// A technique registered by another crate, identified by its name
+    Custom(&'static str),
 }
 
 impl Strategy {
@@ -62,27 +139,138 @@ impl Strategy {
             Strategy::ObviousPair => "Obvious Pair",
             Strategy::HiddenPair => "Hidden Pair",
             Strategy::ObviousTriplet => "Obvious Triplet",
+            Strategy::HiddenTriplet => "Hidden Triplet",
+            Strategy::ObviousQuad => "Obvious Quad",
+            Strategy::HiddenQuad => "Hidden Quad",
             Strategy::Skyscraper => "Skyscraper",
             Strategy::XWing => "X-Wing",
+            Strategy::Swordfish => "Swordfish",
+            Strategy::Jellyfish => "Jellyfish",
+            Strategy::FinnedXWing => "Finned X-Wing",
+            Strategy::FinnedSwordfish => "Finned Swordfish",
+            Strategy::FinnedJellyfish => "Finned Jellyfish",
+            Strategy::SashimiXWing => "Sashimi X-Wing",
+            Strategy::SashimiSwordfish => "Sashimi Swordfish",
+            Strategy::SashimiJellyfish => "Sashimi Jellyfish",
+            Strategy::XYWing => "XY-Wing",
+            Strategy::XYZWing => "XYZ-Wing",
+            Strategy::WWing => "W-Wing",
+            Strategy::TurbotFish => "Turbot Fish",
+            Strategy::TwoStringKite => "2-String Kite",
+            Strategy::EmptyRectangle => "Empty Rectangle",
+            Strategy::UniqueRectangleType1 => "Unique Rectangle Type 1",
+            Strategy::UniqueRectangleType2 => "Unique Rectangle Type 2",
+            Strategy::UniqueRectangleType3 => "Unique Rectangle Type 3",
+            Strategy::UniqueRectangleType4 => "Unique Rectangle Type 4",
+            Strategy::UniqueRectangleType5 => "Unique Rectangle Type 5",
+            Strategy::UniqueRectangleType6 => "Unique Rectangle Type 6",
+            Strategy::HiddenUniqueRectangle => "Hidden Unique Rectangle",
+            Strategy::BugPlusOne => "BUG+1",
+            Strategy::SimpleColorsTrap => "Simple Colors Trap",
+            Strategy::SimpleColorsWrap => "Simple Colors Wrap",
+            Strategy::MultiColors => "Multi Colors",
+            Strategy::Medusa3D => "3D Medusa",
+            Strategy::XChain => "X-Chain",
+            Strategy::XYChain => "XY-Chain",
+            Strategy::ContinuousNiceLoop => "Continuous Nice Loop",
+            Strategy::DiscontinuousNiceLoop => "Discontinuous Nice Loop",
+            Strategy::Aic => "AIC",
+            Strategy::GroupedContinuousNiceLoop => "Grouped Continuous Nice Loop",
+            Strategy::GroupedDiscontinuousNiceLoop => "Grouped Discontinuous Nice Loop",
+            Strategy::GroupedAic => "Grouped AIC",
+            Strategy::AlsXz => "ALS-XZ",
+            Strategy::AlsXyWing => "ALS-XY-Wing",
+            Strategy::DeathBlossom => "Death Blossom",
+            Strategy::SueDeCoq => "Sue de Coq",
+            Strategy::CellForcingChain => "Cell Forcing Chain",
+            Strategy::UnitForcingChain => "Unit Forcing Chain",
+            Strategy::ContradictionForcingNet => "Contradiction Forcing Net",
+            Strategy::PatternOverlay => "Pattern Overlay",
+            Strategy::BowmansBingo => "Bowman's Bingo",
+            Strategy::Custom(name) => name,
         }
     }
 
-    fn difficulty(&self) -> i32 {
+    /// Effort of the technique as implemented by this crate; custom techniques only have
+    /// the effort they are registered with
+    fn builtin_effort(&self) -> Option<i32> {
         match self {
-            Strategy::None => 0,
-            Strategy::LastDigit => 4,
-            Strategy::ObviousSingle => 5,
-            Strategy::HiddenSingle => 14,
-            Strategy::LockedPair => 40,
-            Strategy::PointingPair => 50,
-            Strategy::ClaimingPair => 50,
-            Strategy::ObviousPair => 60,
-            Strategy::HiddenPair => 70,
-            Strategy::ObviousTriplet => 80,
-            Strategy::Skyscraper => 130,
-            Strategy::XWing => 140,
+            Strategy::None => Some(0),
+            Strategy::LastDigit => Some(4),
+            Strategy::ObviousSingle => Some(5),
+            Strategy::HiddenSingle => Some(14),
+            Strategy::LockedPair => Some(40),
+            Strategy::PointingPair => Some(50),
+            Strategy::ClaimingPair => Some(50),
+            Strategy::ObviousPair => Some(60),
+            Strategy::HiddenPair => Some(70),
+            Strategy::ObviousTriplet => Some(80),
+            Strategy::HiddenTriplet => Some(100),
+            Strategy::ObviousQuad => Some(120),
+            Strategy::HiddenQuad => Some(150),
+            Strategy::Skyscraper => Some(130),
+            Strategy::XWing => Some(140),
+            Strategy::Swordfish => Some(150),
+            Strategy::Jellyfish => Some(160),
+            Strategy::FinnedXWing => Some(130),
+            Strategy::FinnedSwordfish => Some(200),
+            Strategy::FinnedJellyfish => Some(250),
+            Strategy::SashimiXWing => Some(150),
+            Strategy::SashimiSwordfish => Some(240),
+            Strategy::SashimiJellyfish => Some(260),
+            Strategy::XYWing => Some(160),
+            Strategy::XYZWing => Some(180),
+            Strategy::WWing => Some(150),
+            Strategy::TurbotFish => Some(120),
+            Strategy::TwoStringKite => Some(150),
+            Strategy::EmptyRectangle => Some(120),
+            Strategy::UniqueRectangleType1 => Some(100),
+            Strategy::UniqueRectangleType2 => Some(100),
+            Strategy::UniqueRectangleType3 => Some(100),
+            Strategy::UniqueRectangleType4 => Some(100),
+            Strategy::UniqueRectangleType5 => Some(100),
+            Strategy::UniqueRectangleType6 => Some(100),
+            Strategy::HiddenUniqueRectangle => Some(100),
+            Strategy::BugPlusOne => Some(100),
+            Strategy::SimpleColorsTrap => Some(150),
+            Strategy::SimpleColorsWrap => Some(150),
+            Strategy::MultiColors => Some(200),
+            Strategy::Medusa3D => Some(220),
+            Strategy::XChain => Some(260),
+            Strategy::XYChain => Some(260),
+            Strategy::ContinuousNiceLoop => Some(280),
+            Strategy::DiscontinuousNiceLoop => Some(280),
+            Strategy::Aic => Some(280),
+            Strategy::GroupedContinuousNiceLoop => Some(300),
+            Strategy::GroupedDiscontinuousNiceLoop => Some(300),
+            Strategy::GroupedAic => Some(300),
+            Strategy::AlsXz => Some(300),
+            Strategy::AlsXyWing => Some(320),
+            Strategy::DeathBlossom => Some(360),
+            Strategy::SueDeCoq => Some(250),
+            Strategy::CellForcingChain => Some(500),
+            Strategy::UnitForcingChain => Some(500),
+            Strategy::ContradictionForcingNet => Some(700),
+            Strategy::PatternOverlay => Some(800),
+            Strategy::BowmansBingo => Some(1000),
+            Strategy::Custom(_) => None,
         }
     }
+
+    /// Strategies that are only valid if the puzzle has exactly one solution
+    pub fn requires_unique_solution(&self) -> bool {
+        matches!(
+            self,
+            Strategy::UniqueRectangleType1
+                | Strategy::UniqueRectangleType2
+                | Strategy::UniqueRectangleType3
+                | Strategy::UniqueRectangleType4
+                | Strategy::UniqueRectangleType5
+                | Strategy::UniqueRectangleType6
+                | Strategy::HiddenUniqueRectangle
+                | Strategy::BugPlusOne
+        )
+    }
 }
 
 impl fmt::Display for Strategy {
@@ -91,26 +279,8 @@ impl fmt::Display for Strategy {
     }
 }
 
-type StrategyApplicator = Vec<(Strategy, fn(&Sudoku) -> StrategyResult)>;
-static STRATEGY_FUNCTIONS: LazyLock<StrategyApplicator> = LazyLock::new(|| {
-    let mut strategies: StrategyApplicator = vec![
-        (Strategy::LastDigit, Sudoku::find_last_digit),
-        (Strategy::ObviousSingle, Sudoku::find_obvious_single),
-        (Strategy::HiddenSingle, Sudoku::find_hidden_single),
-        (Strategy::PointingPair, Sudoku::find_pointing_pair),
-        (Strategy::ClaimingPair, Sudoku::find_claiming_pair),
-        (Strategy::ObviousPair, Sudoku::find_obvious_pair),
-        (Strategy::HiddenPair, Sudoku::find_hidden_pair),
-        (Strategy::ObviousTriplet, Sudoku::find_obvious_triplet),
-        (Strategy::Skyscraper, Sudoku::find_skyscraper),
-        (Strategy::XWing, Sudoku::find_xwing),
-    ];
-    // Sort strategies by difficulty to pre-empt developers from adding strategies in the wrong order
-    strategies.sort_by_key(|(strategy, _)| strategy.difficulty());
-    strategies
-});
 pub const EMPTY: u8 = 0;
-pub static ALL_DIGITS: LazyLock<HashSet<u8>> = LazyLock::new(|| (1..=9).collect());
+pub const ALL_DIGITS: CandidateSet = CandidateSet::all();
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Candidate {
@@ -119,6 +289,29 @@ pub struct Candidate {
     pub num: u8,
 }
 
+/// Placement of one digit on the whole board: the column of the digit in each row
+pub type Template = [usize; 9];
+
+/// All templates of a digit that fit the board, laid on top of each other
+#[derive(Debug, Clone)]
+pub struct TemplateOverlay {
+    pub num: u8,
+    /// Number of templates that fit the board
+    pub templates: usize,
+    /// Empty cells covered by every template: the digit has to go there
+    pub cells_to_set: Vec<(usize, usize)>,
+    /// Candidates covered by no template: the digit can't go there
+    pub candidates_to_remove: Vec<Candidate>,
+}
+
+/// Two candidates for the same digit that are the only ones in a unit (a conjugate pair).
+/// If one of them is false, the other one has to be true.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StrongLink {
+    pub(crate) base: Candidate,
+    pub(crate) top: Candidate,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Cell {
     pub row: usize,
@@ -133,6 +326,18 @@ pub struct RemovalResult {
     pub candidates_about_to_be_removed: HashSet<Candidate>,
     pub unit: Option<Unit>,
     pub unit_index: Option<Vec<usize>>,
+    /// Cover units of fish patterns; `unit` and `unit_index` hold the base units
+    pub cover_unit: Option<Unit>,
+    pub cover_unit_index: Option<Vec<usize>>,
+    /// Fin candidates of finned and sashimi fish
+    pub fins: Vec<Candidate>,
+    /// Color assignment of coloring strategies: candidates with the index of their color class
+    pub colored_candidates: Vec<(Candidate, usize)>,
+    /// Ordered nodes of chaining strategies, alternating strong and weak links starting with a strong one.
+    /// A node is a single candidate, or several candidates of one digit for grouped nodes.
+    pub chain: Vec<Vec<Candidate>>,
+    /// Nesting depth of the guesses of trial-and-error strategies
+    pub trial_depth: usize,
 }
 
 impl RemovalResult {
@@ -143,6 +348,12 @@ impl RemovalResult {
             candidates_about_to_be_removed: HashSet::new(),
             unit: None,
             unit_index: None,
+            cover_unit: None,
+            cover_unit_index: None,
+            fins: Vec::new(),
+            colored_candidates: Vec::new(),
+            chain: Vec::new(),
+            trial_depth: 0,
         }
     }
     pub fn will_remove_candidates(&self) -> bool {
@@ -154,6 +365,12 @@ impl RemovalResult {
         self.candidates_about_to_be_removed.clear();
         self.unit = None;
         self.unit_index = None;
+        self.cover_unit = None;
+        self.cover_unit_index = None;
+        self.fins.clear();
+        self.colored_candidates.clear();
+        self.chain.clear();
+        self.trial_depth = 0;
     }
 }
 
@@ -186,6 +403,8 @@ impl StrategyResult {
 pub struct Resolution {
     pub nums_removed: usize,
     pub strategy: Strategy,
+    /// Effort the step added to the rating
+    pub effort: i32,
 }
 
 impl Resolution {
@@ -195,6 +414,9 @@ impl Resolution {
     pub fn strategy(&self) -> Strategy {
         self.strategy
     }
+    pub fn effort(&self) -> i32 {
+        self.effort
+    }
 }
 
 // Define a custom error for invalid Sudoku board
@@ -211,13 +433,49 @@ impl std::fmt::Display for SudokuError {
 
 impl std::error::Error for SudokuError {}
 
+/// Options that change which strategies the human-like solver may use
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolverOptions {
+    /// Allow strategies that rely on the puzzle having a unique solution, e.g. Unique
+    /// Rectangles. Turn this off for puzzles that are not known to be unique.
+    pub assume_unique_solution: bool,
+    /// Maximum number of candidates in a chain searched by chaining strategies
+    pub max_chain_length: usize,
+    /// Maximum nesting depth of guesses for trial and error; 0 disables guessing
+    pub max_trial_depth: usize,
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self {
+        SolverOptions {
+            assume_unique_solution: true,
+            max_chain_length: 12,
+            max_trial_depth: 3,
+        }
+    }
+}
+
+/// The state of the board before a step was applied, to undo the step
+#[derive(Debug, Clone)]
+pub struct UndoState {
+    pub board: [[u8; 9]; 9],
+    pub candidates: [[CandidateSet; 9]; 9],
+    pub rating: HashMap<Strategy, StrategyRating>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Sudoku {
     pub board: [[u8; 9]; 9],
     pub original_board: [[u8; 9]; 9],
-    pub candidates: [[HashSet<u8>; 9]; 9],
-    pub rating: HashMap<Strategy, usize>,
-    pub undo_stack: Vec<Sudoku>,
+    pub candidates: [[CandidateSet; 9]; 9],
+    /// Effort of the steps applied so far, by technique (see `RatingReport`)
+    pub rating: HashMap<Strategy, StrategyRating>,
+    pub undo_stack: Vec<UndoState>,
+    pub options: SolverOptions,
+    /// Techniques used by the human-like solver
+    pub registry: Arc<StrategyRegistry>,
+    /// Which of the registered techniques are used, in which order and with which effort
+    pub config: SolverConfig,
 }
 
 impl fmt::Display for Sudoku {
@@ -249,9 +507,12 @@ impl Sudoku {
         Sudoku {
             board: [[EMPTY; 9]; 9],
             original_board: [[EMPTY; 9]; 9],
-            candidates: std::array::from_fn(|_| std::array::from_fn(|_| HashSet::new())),
+            candidates: [[CandidateSet::new(); 9]; 9],
             rating: HashMap::new(),
             undo_stack: Vec::new(),
+            options: SolverOptions::default(),
+            registry: StrategyRegistry::shared_builtin(),
+            config: SolverConfig::default(),
         }
     }
 
@@ -265,14 +526,17 @@ impl Sudoku {
         Sudoku {
             board,
             original_board: board,
-            candidates: std::array::from_fn(|_| std::array::from_fn(|_| HashSet::new())),
+            candidates: [[CandidateSet::new(); 9]; 9],
             rating: HashMap::new(),
             undo_stack: Vec::new(),
+            options: SolverOptions::default(),
+            registry: StrategyRegistry::shared_builtin(),
+            config: SolverConfig::default(),
         }
     }
 
     pub fn clear(&mut self) {
-        self.candidates = std::array::from_fn(|_| std::array::from_fn(|_| HashSet::new()));
+        self.candidates = [[CandidateSet::new(); 9]; 9];
         self.board = [[EMPTY; 9]; 9];
         self.rating.clear();
     }
@@ -293,30 +557,6 @@ impl Sudoku {
             .collect()
     }
 
-    #[cfg(feature = "dump")]
-    pub fn dump_rating(&self) {
-        println!("Rating:");
-        let candidates_removed = self.rating.iter().map(|(_, &count)| count).sum::<usize>();
-        let total_rating: i32 = self
-            .rating
-            .iter()
-            .map(|(strategy, &count)| strategy.difficulty() * count as i32)
-            .sum();
-        let difficulty = (total_rating as f64) / (candidates_removed as f64);
-        println!("  Difficulty: {:.2}", difficulty);
-        println!("  Total candidates removed: {}; by …", candidates_removed);
-        let mut strategies: Vec<(&Strategy, &usize)> = self.rating.iter().collect();
-        strategies.sort_by_key(|(strategy, _)| strategy.difficulty());
-        for (strategy, count) in strategies {
-            println!(
-                "  - {} ({}): {}",
-                strategy.to_string(),
-                strategy.difficulty(),
-                count
-            );
-        }
-    }
-
     #[cfg(feature = "dump")]
     pub fn dump_notes(&self) {
         println!();
@@ -332,7 +572,7 @@ impl Sudoku {
                 for j in 0..9 {
                     for k in 0..3 {
                         let num = 3 * line + k + 1;
-                        if self.candidates[i][j].contains(&num) {
+                        if self.candidates[i][j].contains(num) {
                             print!("{}", num);
                         } else {
                             print!(".");
@@ -356,14 +596,10 @@ impl Sudoku {
         }
     }
 
-    pub fn effort(&self) -> f64 {
-        let candidates_removed = self.rating.iter().map(|(_, &count)| count).sum::<usize>();
-        let total_rating: i32 = self
-            .rating
-            .iter()
-            .map(|(strategy, &count)| strategy.difficulty() * count as i32)
-            .sum();
-        (total_rating as f64) / (candidates_removed as f64)
+    /// Effort of removing one candidate with the technique, according to the solver's config.
+    /// `None` for custom techniques that are neither registered nor configured.
+    pub fn strategy_effort(&self, strategy: Strategy) -> Option<i32> {
+        self.config.effort(strategy, &self.registry)
     }
 
     pub fn unsolved(&self) -> bool {
@@ -374,7 +610,7 @@ impl Sudoku {
         !self.unsolved()
     }
 
-    pub fn rating(&self) -> HashMap<Strategy, usize> {
+    pub fn rating(&self) -> HashMap<Strategy, StrategyRating> {
         self.rating.clone()
     }
 
@@ -407,12 +643,8 @@ impl Sudoku {
         for row in self.candidates.iter() {
             for candidates in row.iter() {
                 for digit in (1..=9).step_by(2) {
-                    let high_nibble = if candidates.contains(&digit) {
-                        digit
-                    } else {
-                        0
-                    };
-                    let low_nibble = if candidates.contains(&(digit + 1)) {
+                    let high_nibble = if candidates.contains(digit) { digit } else { 0 };
+                    let low_nibble = if candidates.contains(digit + 1) {
                         digit + 1
                     } else {
                         0
@@ -449,10 +681,10 @@ impl Sudoku {
         }
         sudoku.board = board;
         // Then come 405 bytes for the candidates
-        let mut notes = std::array::from_fn(|_| std::array::from_fn(|_| HashSet::new()));
+        let mut notes = [[CandidateSet::new(); 9]; 9];
         for row in &mut notes {
             for cell_notes in row.iter_mut() {
-                let mut candidates = HashSet::new();
+                let mut candidates = CandidateSet::new();
                 for _ in (0..9).step_by(2) {
                     let byte = binary[idx];
                     let high_nibble = (byte >> 4) & 0xF;
@@ -571,7 +803,7 @@ impl Sudoku {
             for (i, row) in board.iter().enumerate() {
                 if let Some(row_array) = row.as_array() {
                     for (j, cell) in row_array.iter().enumerate() {
-                        if let Some(num) = cell.as_u64() {
+                        if let Some(num) = cell.as_u64().filter(|&num| num <= 9) {
                             sudoku.board[i][j] = num as u8;
                         } else {
                             return Err(SudokuError {
@@ -595,10 +827,14 @@ impl Sudoku {
                 if let Some(row_array) = row.as_array() {
                     for (j, cell_candidates) in row_array.iter().enumerate() {
                         if let Some(candidate_array) = cell_candidates.as_array() {
-                            sudoku.candidates[i][j] = candidate_array
-                                .iter()
-                                .filter_map(|c| c.as_u64().map(|n| n as u8))
-                                .collect();
+                            let nums: Vec<u64> =
+                                candidate_array.iter().filter_map(|c| c.as_u64()).collect();
+                            if nums.iter().any(|n| !(1..=9).contains(n)) {
+                                return Err(SudokuError {
+                                    message: "Candidates must be digits from 1 to 9".to_string(),
+                                });
+                            }
+                            sudoku.candidates[i][j] = nums.iter().map(|&n| n as u8).collect();
                         } else {
                             return Err(SudokuError {
                                 message: "Invalid candidates data in JSON".to_string(),
@@ -630,6 +866,14 @@ impl Sudoku {
         3 * (row / 3) + col / 3
     }
 
+    /// Check if two different cells share a row, column or box
+    fn cells_see_each_other(cell1: (usize, usize), cell2: (usize, usize)) -> bool {
+        cell1 != cell2
+            && (cell1.0 == cell2.0
+                || cell1.1 == cell2.1
+                || Self::get_box_index(cell1.0, cell1.1) == Self::get_box_index(cell2.0, cell2.1))
+    }
+
     /// Calculate the start row and column of the box containing the cell at `row`, `col`
     fn get_box_start(row: usize, col: usize) -> (usize, usize) {
         (3 * (row / 3), 3 * (col / 3))
@@ -640,8 +884,45 @@ impl Sudoku {
         (3 * (box_index / 3), 3 * (box_index % 3))
     }
 
+    /// Collect the coordinates of all cells in the row, column or box at `index`
+    fn get_unit_cells(unit: &Unit, index: usize) -> Vec<(usize, usize)> {
+        match unit {
+            Unit::Row => (0..9).map(|col| (index, col)).collect(),
+            Unit::Column => (0..9).map(|row| (row, index)).collect(),
+            Unit::Box => {
+                let (start_row, start_col) = Self::get_box_start_from_index(index);
+                (0..9)
+                    .map(|i| (start_row + i / 3, start_col + i % 3))
+                    .collect()
+            }
+        }
+    }
+
+    /// Collect all strong links (conjugate pairs) for `num`: units in which the digit
+    /// is a candidate in exactly two cells. One of both cells has to hold the digit.
+    fn find_strong_links(&self, num: u8) -> Vec<(StrongLink, Unit)> {
+        let mut links = Vec::new();
+        for unit in [Unit::Row, Unit::Column, Unit::Box] {
+            for index in 0..9 {
+                let cells: Vec<Candidate> = Self::get_unit_cells(&unit, index)
+                    .into_iter()
+                    .filter(|&(row, col)| self.candidates[row][col].contains(num))
+                    .map(|(row, col)| Candidate { row, col, num })
+                    .collect();
+                if cells.len() == 2 {
+                    let link = StrongLink {
+                        base: cells[0],
+                        top: cells[1],
+                    };
+                    links.push((link, unit.clone()));
+                }
+            }
+        }
+        links
+    }
+
     /// Collect all the certain numbers in a row
-    fn collect_nums_in_row(&self, row: usize) -> HashSet<u8> {
+    fn collect_nums_in_row(&self, row: usize) -> CandidateSet {
         (0..9)
             .filter_map(|col| {
                 let num = self.board[row][col];
@@ -651,7 +932,7 @@ impl Sudoku {
     }
 
     /// Collect all the certain numbers in a column
-    fn collect_nums_in_col(&self, col: usize) -> HashSet<u8> {
+    fn collect_nums_in_col(&self, col: usize) -> CandidateSet {
         (0..9)
             .filter_map(|row| {
                 let num = self.board[row][col];
@@ -661,7 +942,7 @@ impl Sudoku {
     }
 
     /// Collect all the certain numbers in a box
-    fn collect_nums_in_box(&self, box_index: usize) -> HashSet<u8> {
+    fn collect_nums_in_box(&self, box_index: usize) -> CandidateSet {
         let (start_row, start_col) = Self::get_box_start_from_index(box_index);
         (0..3)
             .flat_map(|i| (0..3).map(move |j| (start_row + i, start_col + j)))
@@ -681,9 +962,9 @@ impl Sudoku {
 
     pub fn calc_candidates(&mut self) {
         // First calculate all the "used numbers" sets
-        let mut nums_in_row: [HashSet<u8>; 9] = std::array::from_fn(|_| HashSet::new());
-        let mut nums_in_col: [HashSet<u8>; 9] = std::array::from_fn(|_| HashSet::new());
-        let mut nums_in_box: [HashSet<u8>; 9] = std::array::from_fn(|_| HashSet::new());
+        let mut nums_in_row = [CandidateSet::new(); 9];
+        let mut nums_in_col = [CandidateSet::new(); 9];
+        let mut nums_in_box = [CandidateSet::new(); 9];
         for i in 0..9 {
             nums_in_row[i] = self.collect_nums_in_row(i);
             nums_in_col[i] = self.collect_nums_in_col(i);
@@ -696,18 +977,11 @@ impl Sudoku {
                     return;
                 }
                 let box_idx = 3 * (row / 3) + col / 3;
-                let mut candidates = ALL_DIGITS.clone();
                 // Remove numbers already present in row, column, and box
-                for &num in &nums_in_row[row] {
-                    candidates.remove(&num);
-                }
-                for &num in &nums_in_col[col] {
-                    candidates.remove(&num);
-                }
-                for &num in &nums_in_box[box_idx] {
-                    candidates.remove(&num);
-                }
-                self.candidates[row][col] = candidates;
+                self.candidates[row][col] = ALL_DIGITS
+                    .difference(&nums_in_row[row])
+                    .difference(&nums_in_col[col])
+                    .difference(&nums_in_box[box_idx]);
             })
         });
     }
@@ -864,17 +1138,30 @@ impl Sudoku {
         Self::get_solution_count_for(sudoku) == 1
     }
 
-    #[allow(dead_code)]
     fn find_cells_with_candidate_in_box(&self, box_idx: usize, num: u8) -> Vec<(usize, usize)> {
         let (start_row, start_col) = Self::get_box_start_from_index(box_idx);
         (0..3)
             .flat_map(|r| (0..3).map(move |c| (start_row + r, start_col + c)))
-            .filter(|&(row, col)| self.candidates[row][col].contains(&num))
+            .filter(|&(row, col)| self.candidates[row][col].contains(num))
             .collect()
     }
 
+    /// Build all combinations of `k` items, preserving the order of `items`.
+    fn combinations<T: Copy>(items: &[T], k: usize) -> Vec<Vec<T>> {
+        if k == 0 {
+            return vec![Vec::new()];
+        }
+        let mut combinations = Vec::new();
+        for i in 0..items.len() {
+            for mut rest in Self::combinations(&items[i + 1..], k - 1) {
+                rest.insert(0, items[i]);
+                combinations.push(rest);
+            }
+        }
+        combinations
+    }
+
     /// Check if all cells are in the same row.
-    #[allow(dead_code)]
     fn cells_in_same_row(cells: &[(usize, usize)]) -> Option<usize> {
         let rows: HashSet<_> = cells.iter().map(|&(row, _)| row).collect();
         if rows.len() == 1 {
@@ -885,7 +1172,6 @@ impl Sudoku {
     }
 
     /// Check if all cells are in the same column.
-    #[allow(dead_code)]
     fn cells_in_same_column(cells: &[(usize, usize)]) -> Option<usize> {
         let cols: HashSet<_> = cells.iter().map(|&(_, col)| col).collect();
         if cols.len() == 1 {
@@ -900,7 +1186,7 @@ impl Sudoku {
         let mut result = RemovalResult::empty();
         for col in 0..9 {
             for &num in nums {
-                if self.candidates[row][col].contains(&num) {
+                if self.candidates[row][col].contains(num) {
                     result
                         .candidates_about_to_be_removed
                         .insert(Candidate { row, col, num });
@@ -915,7 +1201,7 @@ impl Sudoku {
         let mut result = RemovalResult::empty();
         for row in 0..9 {
             for &num in nums {
-                if self.candidates[row][col].contains(&num) {
+                if self.candidates[row][col].contains(num) {
                     result
                         .candidates_about_to_be_removed
                         .insert(Candidate { row, col, num });
@@ -935,7 +1221,7 @@ impl Sudoku {
                 let row = start_row + i;
                 let col = start_col + j;
                 for &num in nums {
-                    if self.candidates[row][col].contains(&num) {
+                    if self.candidates[row][col].contains(num) {
                         result
                             .candidates_about_to_be_removed
                             .insert(Candidate { row, col, num });
@@ -977,8 +1263,8 @@ impl Sudoku {
         self.board[row][col]
     }
 
-    pub fn get_candidates(&self, row: usize, col: usize) -> HashSet<u8> {
-        self.candidates[row][col].clone()
+    pub fn get_candidates(&self, row: usize, col: usize) -> CandidateSet {
+        self.candidates[row][col]
     }
 
     /// Collect all candidates that are about to be removed when setting a digit in a cell.
@@ -991,7 +1277,7 @@ impl Sudoku {
             candidates_about_to_be_removed: {
                 let mut candidates = removal_result.candidates_about_to_be_removed;
                 candidates.insert(Candidate { row, col, num });
-                for &n in &self.candidates[row][col] {
+                for n in self.candidates[row][col] {
                     if n != num {
                         candidates.insert(Candidate { row, col, num: n });
                     }
@@ -1000,6 +1286,12 @@ impl Sudoku {
             },
             unit: None,
             unit_index: None,
+            cover_unit: None,
+            cover_unit_index: None,
+            fins: Vec::new(),
+            colored_candidates: Vec::new(),
+            chain: Vec::new(),
+            trial_depth: 0,
         }
     }
 
@@ -1009,11 +1301,15 @@ impl Sudoku {
             "Applying strategy: {}",
             strategy_result.strategy.to_string()
         );
-        let mut clone = self.clone();
-        clone.undo_stack.clear(); // Don't clone the undo stack
-        self.undo_stack.push(clone);
+        self.undo_stack.push(UndoState {
+            board: self.board,
+            candidates: self.candidates,
+            rating: self.rating.clone(),
+        });
+        let effort = self.rate_step(strategy_result);
+        let mut nums_removed = 0;
         for candidate in &strategy_result.removals.candidates_about_to_be_removed {
-            if !self.candidates[candidate.row][candidate.col].contains(&candidate.num) {
+            if !self.candidates[candidate.row][candidate.col].contains(candidate.num) {
                 log::error!(
                     "Trying to remove candidate {} at ({}, {}) that doesn't exist\nStrategy: {}\nOriginal board: {}\nBacktrace: {}",
                     candidate.num,
@@ -1024,23 +1320,17 @@ impl Sudoku {
                     std::backtrace::Backtrace::capture()
                 );
             } else {
-                self.candidates[candidate.row][candidate.col].remove(&candidate.num);
+                self.candidates[candidate.row][candidate.col].remove(candidate.num);
+                nums_removed += 1;
             }
         }
         if let Some(cell) = &strategy_result.removals.sets_cell {
             self.board[cell.row][cell.col] = cell.num;
-            // Update rating for this strategy
-            self.rating
-                .entry(strategy_result.strategy)
-                .and_modify(|count| *count += 1)
-                .or_insert(1);
         }
         Resolution {
-            nums_removed: strategy_result
-                .removals
-                .candidates_about_to_be_removed
-                .len(),
+            nums_removed,
             strategy: strategy_result.strategy,
+            effort,
         }
     }
 
@@ -1050,84 +1340,86 @@ impl Sudoku {
         Resolution {
             nums_removed: 0,
             strategy: Strategy::None,
+            effort: 0,
         }
     }
 
-    /// Find the next step to solve the Sudoku puzzle.
-    pub fn next_step(&mut self) -> StrategyResult {
-        for (strategy, strategy_fn) in STRATEGY_FUNCTIONS.iter() {
-            let result = (strategy_fn)(self);
+    /// Find the next step to solve the Sudoku puzzle, trying the techniques in the order
+    /// given by `config`.
+    pub fn next_step(&self) -> StrategyResult {
+        for solving_strategy in self.config.strategies(&self.registry) {
+            if solving_strategy.requires_unique_solution() && !self.options.assume_unique_solution {
+                continue;
+            }
+            let result = solving_strategy.find_one(self);
             if !result.removals.will_remove_candidates() {
                 continue;
             }
-            let nums_removed = result.removals.candidates_about_to_be_removed.len();
-            self.rating
-                .entry(*strategy)
-                .and_modify(|count| *count += nums_removed)
-                .or_insert(nums_removed);
             return StrategyResult {
                 removals: result.removals,
-                strategy: *strategy,
+                strategy: solving_strategy.strategy(),
             };
         }
         StrategyResult::empty()
     }
 
-    /// Build a list of all possible techniques to solve the Sudoku puzzle at the current state.
+    /// Build a list of all techniques that make progress at the current state, with the
+    /// results of `SolvingStrategy::find_all`: one result per built-in technique.
     pub fn all_possible_strategies(&self) -> Vec<StrategyResult> {
         let mut strategies = Vec::new();
-        for (strategy, strategy_fn) in STRATEGY_FUNCTIONS.iter() {
-            let result = (strategy_fn)(self);
-            if !result.removals.will_remove_candidates() {
+        for solving_strategy in self.config.strategies(&self.registry) {
+            if solving_strategy.requires_unique_solution() && !self.options.assume_unique_solution {
                 continue;
             }
-            strategies.push(StrategyResult {
-                removals: result.removals,
-                strategy: *strategy,
-            });
+            strategies.extend(solving_strategy.find_all(self));
         }
         strategies
     }
 
-    /// Solve the Sudoku puzzle using human-like strategies
+    /// Solve the Sudoku puzzle using human-like strategies, printing the board after each step
     #[cfg(feature = "dump")]
-    fn solve_like_a_human(&mut self) -> bool {
+    fn solve_like_a_human(&mut self) -> SolvePath {
         // The first step always is to calculate the notes
         self.calc_candidates();
         // Since we're starting from scratch, we clear the rating
         self.rating.clear();
+        let mut path = SolvePath::new(self.to_board_string());
         while self.unsolved() {
             let result = self.next_step();
             if result.strategy == Strategy::None {
                 // No applicable strategy found or Sudoku is solved
                 break;
             }
-            self.apply(&result);
+            path.steps.push(SolveStep::record(self, &result));
             self.print();
             self.dump_notes();
         }
-        self.is_solved()
+        path.solved = self.is_solved();
+        path
     }
 
-    pub fn solve_human_like(&mut self) -> bool {
+    /// Solve the Sudoku puzzle using human-like strategies and return the steps taken
+    pub fn solve_human_like(&mut self) -> SolvePath {
         // The first step always is to calculate the notes
         self.calc_candidates();
         // Since we're starting from scratch, we clear the rating
         self.rating.clear();
+        let mut path = SolvePath::new(self.to_board_string());
         while self.unsolved() {
             let result = self.next_step();
             if result.strategy == Strategy::None {
                 // No applicable strategy found or Sudoku is solved
                 break;
             }
-            self.apply(&result);
+            path.steps.push(SolveStep::record(self, &result));
         }
-        self.is_solved()
+        path.solved = self.is_solved();
+        path
     }
 
     #[cfg(feature = "dump")]
-    pub fn solve_puzzle(&mut self) {
-        self.solve_like_a_human();
+    pub fn solve_puzzle(&mut self) -> SolvePath {
+        let path = self.solve_like_a_human();
         println!();
         self.print();
         if self.unsolved() {
@@ -1136,7 +1428,10 @@ impl Sudoku {
         } else {
             println!("\n**** SUDOKU SOLVED ****\n");
         }
-        self.dump_rating();
+        println!("Solve path:");
+        print!("{}", path);
+        print!("{}", self.rating_report());
+        path
     }
 
     pub fn restore(&mut self) {
@@ -0,0 +1,76 @@
+use crate::{
+    Candidate, CandidateSet, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, Unit,
+};
+
+impl Sudoku {
+    /// Locked Pair/Triple: two or three empty cells in the intersection of a box and a line
+    /// hold only as many digits as there are cells. The digits are locked in both units and
+    /// can be removed from the rest of the box and the rest of the line.
+    pub fn find_locked_pair_removals(&self) -> RemovalResult {
+        let mut result = RemovalResult::empty();
+        for box_idx in 0..9 {
+            let (start_row, start_col) = Self::get_box_start_from_index(box_idx);
+            let lines = (0..3)
+                .map(|i| (Unit::Row, start_row + i))
+                .chain((0..3).map(|i| (Unit::Column, start_col + i)));
+            for (line, line_idx) in lines {
+                let intersection: Vec<(usize, usize)> = Self::get_unit_cells(&line, line_idx)
+                    .into_iter()
+                    .filter(|&(row, col)| {
+                        Self::get_box_index(row, col) == box_idx && self.board[row][col] == EMPTY
+                    })
+                    .collect();
+                for size in 2..=intersection.len() {
+                    for cells in Self::combinations(&intersection, size) {
+                        let nums: CandidateSet = cells
+                            .iter()
+                            .flat_map(|&(row, col)| self.candidates[row][col].iter())
+                            .collect();
+                        if nums.len() != size {
+                            continue;
+                        }
+                        let others = Self::get_unit_cells(&line, line_idx)
+                            .into_iter()
+                            .chain(Self::get_unit_cells(&Unit::Box, box_idx))
+                            .filter(|cell| !cells.contains(cell));
+                        for (row, col) in others {
+                            for num in nums {
+                                if self.candidates[row][col].contains(num) {
+                                    result.candidates_about_to_be_removed.insert(Candidate {
+                                        row,
+                                        col,
+                                        num,
+                                    });
+                                }
+                            }
+                        }
+                        if result.will_remove_candidates() {
+                            for &(row, col) in &cells {
+                                result.candidates_affected.extend(
+                                    self.candidates[row][col].iter().map(|num| Candidate {
+                                        row,
+                                        col,
+                                        num,
+                                    }),
+                                );
+                            }
+                            result.unit = Some(Unit::Box);
+                            result.unit_index = Some(vec![box_idx]);
+                            return result;
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Find and resolve Locked Pairs and Locked Triples.
+    pub fn find_locked_pair(&self) -> StrategyResult {
+        log::info!("Finding locked pairs and triples");
+        StrategyResult {
+            strategy: Strategy::LockedPair,
+            removals: self.find_locked_pair_removals(),
+        }
+    }
+}
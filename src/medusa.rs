@@ -0,0 +1,197 @@
+use crate::{Candidate, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku};
+use std::collections::HashMap;
+
+/// A cluster of candidates of any digit connected by strong links, through conjugate pairs
+/// and bivalue cells, colored with two alternating colors: exactly one color is true.
+struct MedusaCluster {
+    colors: [Vec<Candidate>; 2],
+}
+
+impl MedusaCluster {
+    fn color_of(&self, candidate: &Candidate) -> Option<usize> {
+        (0..2).find(|&color| self.colors[color].contains(candidate))
+    }
+
+    /// Check if a candidate of the color class is the same digit in a cell seeing `candidate`
+    fn sees(&self, color: usize, candidate: &Candidate) -> bool {
+        self.colors[color].iter().any(|colored| {
+            colored.num == candidate.num
+                && Sudoku::cells_see_each_other(
+                    (colored.row, colored.col),
+                    (candidate.row, candidate.col),
+                )
+        })
+    }
+
+    /// Check if the color class holds a candidate in the cell
+    fn in_cell(&self, color: usize, row: usize, col: usize) -> bool {
+        self.colors[color]
+            .iter()
+            .any(|colored| (colored.row, colored.col) == (row, col))
+    }
+}
+
+impl Sudoku {
+    /// Strong links between candidates of any digit: conjugate pairs and the two candidates
+    /// of bivalue cells
+    fn medusa_links(&self) -> HashMap<Candidate, Vec<Candidate>> {
+        let mut links: HashMap<Candidate, Vec<Candidate>> = HashMap::new();
+        let mut add_link = |a: Candidate, b: Candidate| {
+            links.entry(a).or_default().push(b);
+            links.entry(b).or_default().push(a);
+        };
+        for num in 1..=9 {
+            for (link, _) in self.find_strong_links(num) {
+                add_link(link.base, link.top);
+            }
+        }
+        for (row, col) in self.cells_with_candidate_count(2) {
+            let nums: Vec<u8> = self.candidates[row][col].iter().collect();
+            add_link(
+                Candidate {
+                    row,
+                    col,
+                    num: nums[0],
+                },
+                Candidate {
+                    row,
+                    col,
+                    num: nums[1],
+                },
+            );
+        }
+        links
+    }
+
+    /// Build all clusters of at least three candidates, assigning alternating colors along
+    /// the links
+    fn find_medusa_clusters(&self) -> Vec<MedusaCluster> {
+        let links = self.medusa_links();
+        let mut starts: Vec<Candidate> = links.keys().cloned().collect();
+        starts.sort_by_key(|c| (c.row, c.col, c.num));
+        let mut clusters: Vec<MedusaCluster> = Vec::new();
+        for start in starts {
+            if clusters
+                .iter()
+                .any(|cluster| cluster.color_of(&start).is_some())
+            {
+                continue;
+            }
+            let mut cluster = MedusaCluster {
+                colors: [vec![start], Vec::new()],
+            };
+            let mut queue = vec![(start, 0)];
+            while let Some((candidate, color)) = queue.pop() {
+                for &next in &links[&candidate] {
+                    if cluster.color_of(&next).is_none() {
+                        cluster.colors[1 - color].push(next);
+                        queue.push((next, 1 - color));
+                    }
+                }
+            }
+            if cluster.colors[0].len() + cluster.colors[1].len() > 2 {
+                clusters.push(cluster);
+            }
+        }
+        clusters
+    }
+
+    /// Find a color of the cluster that can't be true: it appears twice in a cell (rule 1),
+    /// twice for a digit in a unit (rule 2), or it sees all candidates of an uncolored cell
+    /// (rule 6).
+    fn find_medusa_false_color(&self, cluster: &MedusaCluster) -> Option<usize> {
+        for color in 0..2 {
+            let candidates = &cluster.colors[color];
+            for (i, a) in candidates.iter().enumerate() {
+                for b in candidates.iter().skip(i + 1) {
+                    let same_cell = (a.row, a.col) == (b.row, b.col);
+                    let same_unit = a.num == b.num
+                        && Self::cells_see_each_other((a.row, a.col), (b.row, b.col));
+                    if same_cell || same_unit {
+                        return Some(color);
+                    }
+                }
+            }
+        }
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.board[row][col] != EMPTY
+                    || cluster.in_cell(0, row, col)
+                    || cluster.in_cell(1, row, col)
+                {
+                    continue;
+                }
+                for color in 0..2 {
+                    let emptied = self.candidates[row][col]
+                        .iter()
+                        .all(|num| cluster.sees(color, &Candidate { row, col, num }));
+                    if emptied {
+                        return Some(color);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Collect the uncolored candidates that can't be true, as one of both colors is: the
+    /// other candidates of a cell holding both colors (rule 3), candidates seeing the same
+    /// digit in both colors (rule 4), and candidates seeing the digit in one color while
+    /// their cell holds the other color (rule 5).
+    fn collect_medusa_uncolored(&self, cluster: &MedusaCluster, result: &mut RemovalResult) {
+        for row in 0..9 {
+            for col in 0..9 {
+                let both_colors = cluster.in_cell(0, row, col) && cluster.in_cell(1, row, col);
+                for num in self.candidates[row][col] {
+                    let candidate = Candidate { row, col, num };
+                    if cluster.color_of(&candidate).is_some() {
+                        continue;
+                    }
+                    let removable = both_colors
+                        || (cluster.sees(0, &candidate) && cluster.sees(1, &candidate))
+                        || (0..2).any(|color| {
+                            cluster.in_cell(color, row, col) && cluster.sees(1 - color, &candidate)
+                        });
+                    if removable {
+                        result.candidates_about_to_be_removed.insert(candidate);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Find and resolve 3D Medusa.
+    /// Color a cluster of candidates connected by conjugate pairs and bivalue cells with two
+    /// alternating colors, so that exactly one color is true. A color that leads to a
+    /// contradiction is removed; otherwise, candidates that can't be true whichever color is
+    /// true are removed.
+    pub fn find_3d_medusa(&self) -> StrategyResult {
+        log::info!("Finding 3D Medusa");
+        let mut result = RemovalResult::empty();
+        for cluster in self.find_medusa_clusters() {
+            if let Some(color) = self.find_medusa_false_color(&cluster) {
+                result
+                    .candidates_about_to_be_removed
+                    .extend(cluster.colors[color].iter().cloned());
+            } else {
+                self.collect_medusa_uncolored(&cluster, &mut result);
+            }
+            if result.will_remove_candidates() {
+                for color in 0..2 {
+                    for &candidate in &cluster.colors[color] {
+                        result.candidates_affected.push(candidate);
+                        result.colored_candidates.push((candidate, color));
+                    }
+                }
+                return StrategyResult {
+                    strategy: Strategy::Medusa3D,
+                    removals: result,
+                };
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::Medusa3D,
+            removals: result,
+        }
+    }
+}
@@ -35,7 +35,7 @@ impl Sudoku {
                         .map(|c| c.num)
                         .collect::<Vec<_>>()
                     {
-                        if self.candidates[row][col].contains(&num) {
+                        if self.candidates[row][col].contains(num) {
                             result.candidates_about_to_be_removed.insert(Candidate {
                                 row,
                                 col,
@@ -59,7 +59,7 @@ impl Sudoku {
                     continue;
                 }
 
-                let pair = self.candidates[row][col].clone();
+                let pair = self.candidates[row][col];
 
                 // If the pair is not found in exactly two cells, skip
                 if self.candidates[row]
@@ -78,11 +78,11 @@ impl Sudoku {
                     }
                     // Found a pair, mark these candidates from other cells
                     // in the same row as about to be removed
-                    let nums: Vec<u8> = pair.iter().cloned().collect();
+                    let nums: Vec<u8> = pair.iter().collect();
                     for j in 0..9 {
                         if j != col && j != i {
                             for &num in &nums {
-                                if self.candidates[row][j].contains(&num) {
+                                if self.candidates[row][j].contains(num) {
                                     result.candidates_about_to_be_removed.insert(Candidate {
                                         row,
                                         col: j,
@@ -95,10 +95,10 @@ impl Sudoku {
                     if result.will_remove_candidates() {
                         result
                             .candidates_affected
-                            .extend(pair.iter().map(|&num| Candidate { row, col, num }));
+                            .extend(pair.iter().map(|num| Candidate { row, col, num }));
                         result
                             .candidates_affected
-                            .extend(pair.iter().map(|&num| Candidate { row, col: i, num }));
+                            .extend(pair.iter().map(|num| Candidate { row, col: i, num }));
                         result.unit = Some(Unit::Row);
                         result.unit_index = Some(vec![row]);
                         return result;
@@ -118,7 +118,7 @@ impl Sudoku {
                     continue;
                 }
 
-                let pair = self.candidates[row][col].clone();
+                let pair = self.candidates[row][col];
 
                 // If the pair is not found in exactly two cells, skip
                 if self
@@ -138,11 +138,11 @@ impl Sudoku {
                     }
                     // Found a pair, mark these candidates from other cells
                     // in the same column as about to be removed
-                    let nums: Vec<u8> = pair.iter().cloned().collect();
+                    let nums: Vec<u8> = pair.iter().collect();
                     for j in 0..9 {
                         if j != row && j != i {
                             for &num in &nums {
-                                if self.candidates[j][col].contains(&num) {
+                                if self.candidates[j][col].contains(num) {
                                     result.candidates_about_to_be_removed.insert(Candidate {
                                         row: j,
                                         col,
@@ -155,10 +155,10 @@ impl Sudoku {
                     if result.will_remove_candidates() {
                         result
                             .candidates_affected
-                            .extend(pair.iter().map(|&num| Candidate { row, col, num }));
+                            .extend(pair.iter().map(|num| Candidate { row, col, num }));
                         result
                             .candidates_affected
-                            .extend(pair.iter().map(|&num| Candidate { row: i, col, num }));
+                            .extend(pair.iter().map(|num| Candidate { row: i, col, num }));
                         result.unit = Some(Unit::Column);
                         result.unit_index = Some(vec![col]);
                         return result;
@@ -186,7 +186,7 @@ impl Sudoku {
                             continue;
                         }
 
-                        let pair = self.candidates[row1][col1].clone();
+                        let pair = self.candidates[row1][col1];
 
                         for r2 in 0..3 {
                             for c2 in 0..3 {
@@ -203,7 +203,7 @@ impl Sudoku {
                                 }
 
                                 // Found a pair, remove these candidates from other cells in the same box
-                                let nums: Vec<u8> = pair.iter().cloned().collect();
+                                let nums: Vec<u8> = pair.iter().collect();
                                 for r in 0..3 {
                                     for c in 0..3 {
                                         let row = start_row + r;
@@ -212,7 +212,7 @@ impl Sudoku {
                                             && (row != row2 || col != col2)
                                         {
                                             for &num in &nums {
-                                                if self.candidates[row][col].contains(&num) {
+                                                if self.candidates[row][col].contains(num) {
                                                     result
                                                         .candidates_about_to_be_removed
                                                         .insert(Candidate { row, col, num });
@@ -222,7 +222,7 @@ impl Sudoku {
                                     }
                                 }
                                 if result.will_remove_candidates() {
-                                    result.candidates_affected.extend(pair.iter().map(|&num| {
+                                    result.candidates_affected.extend(pair.iter().map(|num| {
                                         Candidate {
                                             row: row1,
                                             col: col1,
@@ -230,7 +230,7 @@ impl Sudoku {
                                         }
                                     }));
                                     result.candidates_affected.extend(
-                                        self.candidates[row2][col2].iter().map(|&num| Candidate {
+                                        self.candidates[row2][col2].iter().map(|num| Candidate {
                                             row: row2,
                                             col: col2,
                                             num,
@@ -252,16 +252,6 @@ impl Sudoku {
     pub fn find_obvious_pair(&self) -> StrategyResult {
         log::info!("Finding obvious pairs in rows");
         let removal_result = self.find_obvious_pair_in_rows();
-        // Check if the pair exists in the same box (locked pair)
-        let mut row_removal = removal_result.clone();
-        if !row_removal.candidates_affected.is_empty()
-            && self.remove_box_candidates(&mut row_removal)
-        {
-            return StrategyResult {
-                strategy: Strategy::LockedPair,
-                removals: row_removal,
-            };
-        }
         if removal_result.will_remove_candidates() {
             return StrategyResult {
                 strategy: Strategy::ObviousPair,
@@ -270,16 +260,6 @@ impl Sudoku {
         }
         log::info!("Finding obvious pairs in columns");
         let removal_result = self.find_obvious_pair_in_cols();
-        // Check if the pair exists in the same box (locked pair)
-        let mut row_removal = removal_result.clone();
-        if !row_removal.candidates_affected.is_empty()
-            && self.remove_box_candidates(&mut row_removal)
-        {
-            return StrategyResult {
-                strategy: Strategy::LockedPair,
-                removals: row_removal,
-            };
-        }
         if removal_result.will_remove_candidates() {
             return StrategyResult {
                 strategy: Strategy::ObviousPair,
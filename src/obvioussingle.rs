@@ -8,7 +8,7 @@ impl Sudoku {
                     continue;
                 }
                 assert_eq!(self.board[row][col], EMPTY);
-                let &num = self.candidates[row][col].iter().next().unwrap();
+                let num = self.candidates[row][col].iter().next().unwrap();
                 return StrategyResult {
                     strategy: Strategy::ObviousSingle,
                     removals: self.collect_set_num(num, row, col),
@@ -0,0 +1,114 @@
+use crate::{
+    Candidate, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, Template, TemplateOverlay,
+};
+use std::sync::LazyLock;
+
+/// All 46656 ways to place a digit on an empty board: one cell per row, column and box
+static ALL_TEMPLATES: LazyLock<Vec<Template>> = LazyLock::new(|| {
+    let mut templates = Vec::with_capacity(46656);
+    add_templates(&mut [0; 9], 0, &mut templates);
+    templates
+});
+
+/// Place the digit in each row from `row` on, in a column and box not used by earlier rows
+fn add_templates(template: &mut Template, row: usize, templates: &mut Vec<Template>) {
+    if row == 9 {
+        templates.push(*template);
+        return;
+    }
+    let band_start = row / 3 * 3;
+    for col in 0..9 {
+        let used_col = template[..row].contains(&col);
+        let used_box = template[band_start..row]
+            .iter()
+            .any(|&other| other / 3 == col / 3);
+        if used_col || used_box {
+            continue;
+        }
+        template[row] = col;
+        add_templates(template, row + 1, templates);
+    }
+}
+
+impl Sudoku {
+    /// Templates of `num` that fit the board: every cell of the template either holds `num`
+    /// already or still has it as a candidate. Each template lists the column of `num` by row.
+    pub fn templates(&self, num: u8) -> Vec<Template> {
+        ALL_TEMPLATES
+            .iter()
+            .filter(|template| {
+                template.iter().enumerate().all(|(row, &col)| {
+                    self.board[row][col] == num
+                        || (self.board[row][col] == EMPTY
+                            && self.candidates[row][col].contains(num))
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Overlay all templates of `num` that fit the board: empty cells covered by every
+    /// template must hold `num`, candidates covered by none of them can be removed.
+    pub fn template_overlay(&self, num: u8) -> TemplateOverlay {
+        let templates = self.templates(num);
+        let mut coverage = [[0usize; 9]; 9];
+        for template in &templates {
+            for (row, &col) in template.iter().enumerate() {
+                coverage[row][col] += 1;
+            }
+        }
+        let mut overlay = TemplateOverlay {
+            num,
+            templates: templates.len(),
+            cells_to_set: Vec::new(),
+            candidates_to_remove: Vec::new(),
+        };
+        // Without any template the board is broken, and nothing can be concluded
+        if templates.is_empty() {
+            return overlay;
+        }
+        for (row, counts) in coverage.iter().enumerate() {
+            for (col, &count) in counts.iter().enumerate() {
+                if self.board[row][col] != EMPTY {
+                    continue;
+                }
+                if count == templates.len() {
+                    overlay.cells_to_set.push((row, col));
+                } else if count == 0 && self.candidates[row][col].contains(num) {
+                    overlay
+                        .candidates_to_remove
+                        .push(Candidate { row, col, num });
+                }
+            }
+        }
+        overlay
+    }
+
+    /// Find and resolve Pattern Overlay (templates).
+    /// A digit's final placement is one of 46656 templates: one cell in each row, column and
+    /// box. Overlaying the templates that still fit the board, a cell covered by all of them
+    /// must hold the digit, and a candidate covered by none of them can be removed.
+    pub fn find_pattern_overlay(&self) -> StrategyResult {
+        log::info!("Finding Pattern Overlay");
+        for num in 1..=9 {
+            let overlay = self.template_overlay(num);
+            let result = if let Some(&(row, col)) = overlay.cells_to_set.first() {
+                self.collect_set_num(num, row, col)
+            } else {
+                let mut result = RemovalResult::empty();
+                for candidate in overlay.candidates_to_remove {
+                    result.candidates_about_to_be_removed.insert(candidate);
+                    result.candidates_affected.push(candidate);
+                }
+                result
+            };
+            if result.will_remove_candidates() {
+                return StrategyResult {
+                    strategy: Strategy::PatternOverlay,
+                    removals: result,
+                };
+            }
+        }
+        StrategyResult::new(Strategy::PatternOverlay)
+    }
+}
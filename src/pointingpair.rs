@@ -12,12 +12,12 @@ impl Sudoku {
                 let box_row = (row / 3) * 3;
                 // Find all cells in this row and within the box that have this candidate
                 let cells_with_num: Vec<(usize, usize)> = (0..9)
-                    .filter(|&col| self.candidates[row][col].contains(&num))
+                    .filter(|&col| self.candidates[row][col].contains(num))
                     .map(|col| (row, col))
                     .filter(|&(_, col)| (col / 3) * 3 == box_row)
                     .collect();
-                // For a pointing pair, we need exactly 2 cells in same box
-                if cells_with_num.len() != 2 {
+                // For a pointing pair or triple, we need 2 or 3 cells in same box
+                if !(2..=3).contains(&cells_with_num.len()) {
                     continue;
                 }
                 // Check if both cells are in the same box
@@ -32,7 +32,7 @@ impl Sudoku {
                 let has_other_cells_with_num = (box_row..box_row + 3)
                     .flat_map(|r| (box_col..box_col + 3).map(move |c| (r, c)))
                     .filter(|&(r, _)| r != row) // Skip cells in our row
-                    .any(|(r, c)| self.candidates[r][c].contains(&num));
+                    .any(|(r, c)| self.candidates[r][c].contains(num));
                 if has_other_cells_with_num {
                     continue;
                 }
@@ -40,7 +40,7 @@ impl Sudoku {
                 // Check if there are candidates to remove in the same row outside the box
                 for col in 0..9 {
                     if (col < box_col || col >= box_col + 3)
-                        && self.candidates[row][col].contains(&num)
+                        && self.candidates[row][col].contains(num)
                     {
                         result
                             .candidates_about_to_be_removed
@@ -71,12 +71,12 @@ impl Sudoku {
                 let box_col = (col / 3) * 3;
                 // Find all cells in this column and within the box that have this candidate
                 let cells_with_num: Vec<(usize, usize)> = (0..9)
-                    .filter(|&row| self.candidates[row][col].contains(&num))
+                    .filter(|&row| self.candidates[row][col].contains(num))
                     .map(|row| (row, col))
                     .filter(|&(row, _)| (row / 3) * 3 == box_col)
                     .collect();
-                // For a pointing pair, we need exactly 2 cells in same box
-                if cells_with_num.len() != 2 {
+                // For a pointing pair or triple, we need 2 or 3 cells in same box
+                if !(2..=3).contains(&cells_with_num.len()) {
                     continue;
                 }
                 // Check if both cells are in the same box
@@ -91,7 +91,7 @@ impl Sudoku {
                 let has_other_cells_with_num = (box_row..box_row + 3)
                     .flat_map(|r| (box_col..box_col + 3).map(move |c| (r, c)))
                     .filter(|&(_, c)| c != col) // Skip cells in our column
-                    .any(|(r, c)| self.candidates[r][c].contains(&num));
+                    .any(|(r, c)| self.candidates[r][c].contains(num));
                 if has_other_cells_with_num {
                     continue;
                 }
@@ -99,7 +99,7 @@ impl Sudoku {
                 // Check if there are candidates to remove in the same column outside the box
                 for row in 0..9 {
                     if (row < box_row || row >= box_row + 3)
-                        && self.candidates[row][col].contains(&num)
+                        && self.candidates[row][col].contains(num)
                     {
                         result
                             .candidates_about_to_be_removed
@@ -133,36 +133,35 @@ impl Sudoku {
                     for c in 0..3 {
                         let row = box_row + r;
                         let col = box_col + c;
-                        if self.candidates[row][col].contains(&num) {
+                        if self.candidates[row][col].contains(num) {
                             cells_with_num.push((row, col));
                         }
                     }
                 }
                 // Skip if none or too many cells have this candidate
-                if cells_with_num.len() != 2 {
+                if !(2..=3).contains(&cells_with_num.len()) {
                     continue;
                 }
                 // Check if all cells with this candidate are in the same row
-                let rows: HashSet<_> = cells_with_num.iter().map(|&(r, _)| r).collect();
-                if rows.len() != 1 {
-                    continue;
-                }
-                let row = *rows.iter().next().unwrap();
-                // See if we can remove this candidate from other cells in the same row
-                for col in 0..9 {
-                    // Skip cells in the current box
-                    if col >= box_col && col < box_col + 3 {
-                        continue;
-                    }
-                    if self.candidates[row][col].contains(&num) {
-                        result
-                            .candidates_about_to_be_removed
-                            .insert(Candidate { row, col, num });
+                if let Some(row) = Self::cells_in_same_row(&cells_with_num) {
+                    // See if we can remove this candidate from other cells in the same row
+                    for col in 0..9 {
+                        // Skip cells in the current box
+                        if col >= box_col && col < box_col + 3 {
+                            continue;
+                        }
+                        if self.candidates[row][col].contains(num) {
+                            result.candidates_about_to_be_removed.insert(Candidate {
+                                row,
+                                col,
+                                num,
+                            });
+                        }
                     }
                 }
                 if result.will_remove_candidates() {
                     // Add the source cells as affected candidates
-                    for &(_, col) in &cells_with_num {
+                    for &(row, col) in &cells_with_num {
                         result.candidates_affected.push(Candidate { row, col, num });
                     }
                     result.unit = Some(Unit::Box);
@@ -170,18 +169,16 @@ impl Sudoku {
                     return result;
                 }
                 // Check if all cells with this candidate are in the same column
-                let cols: HashSet<_> = cells_with_num.iter().map(|&(_, c)| c).collect();
-                if cols.len() != 1 {
+                let Some(col) = Self::cells_in_same_column(&cells_with_num) else {
                     continue;
-                }
-                let col = *cols.iter().next().unwrap();
+                };
                 // See if we can remove this candidate from other cells in the same column
                 for row in 0..9 {
                     // Skip cells in the current box
                     if row >= box_row && row < box_row + 3 {
                         continue;
                     }
-                    if self.candidates[row][col].contains(&num) {
+                    if self.candidates[row][col].contains(num) {
                         result
                             .candidates_about_to_be_removed
                             .insert(Candidate { row, col, num });
@@ -0,0 +1,271 @@
+use crate::{
+    Candidate, CandidateSet, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, Unit,
+};
+use std::collections::HashSet;
+
+impl Sudoku {
+    /// Find an obvious quad among the given cells of a unit: four cells that together
+    /// contain only four different candidates. These candidates can be removed from
+    /// all other cells of the unit.
+    fn find_obvious_quad_in_cells(&self, cells: &[(usize, usize)]) -> RemovalResult {
+        let mut result = RemovalResult::empty();
+        // Only cells with two to four candidates can be part of an obvious quad
+        let quad_cells: Vec<(usize, usize)> = cells
+            .iter()
+            .filter(|&&(row, col)| {
+                self.board[row][col] == EMPTY && (2..=4).contains(&self.candidates[row][col].len())
+            })
+            .cloned()
+            .collect();
+        if quad_cells.len() < 4 {
+            return result;
+        }
+        for i in 0..quad_cells.len() {
+            for j in (i + 1)..quad_cells.len() {
+                for k in (j + 1)..quad_cells.len() {
+                    for l in (k + 1)..quad_cells.len() {
+                        let quad = [quad_cells[i], quad_cells[j], quad_cells[k], quad_cells[l]];
+                        let combined_candidates: CandidateSet = quad
+                            .iter()
+                            .flat_map(|&(row, col)| self.candidates[row][col].iter())
+                            .collect();
+                        if combined_candidates.len() != 4 {
+                            continue;
+                        }
+                        // Remove the quad's candidates from all other cells of the unit
+                        for &(row, col) in cells {
+                            if quad.contains(&(row, col)) {
+                                continue;
+                            }
+                            for num in combined_candidates {
+                                if self.candidates[row][col].contains(num) {
+                                    result.candidates_about_to_be_removed.insert(Candidate {
+                                        row,
+                                        col,
+                                        num,
+                                    });
+                                }
+                            }
+                        }
+                        if result.will_remove_candidates() {
+                            result.candidates_affected.extend(quad.iter().flat_map(
+                                |&(row, col)| {
+                                    self.candidates[row][col].iter().map(move |num| Candidate {
+                                        row,
+                                        col,
+                                        num,
+                                    })
+                                },
+                            ));
+                            return result;
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Find a hidden quad among the given cells of a unit: four digits that, within
+    /// the unit, only appear as candidates in the same four cells. All other candidates
+    /// can be removed from these cells.
+    fn find_hidden_quad_in_cells(&self, cells: &[(usize, usize)]) -> RemovalResult {
+        let mut result = RemovalResult::empty();
+        // For each digit, track which of the cells have it as a candidate
+        let mut positions_for_digit: [Vec<usize>; 10] = Default::default();
+        for (idx, &(row, col)) in cells.iter().enumerate() {
+            if self.board[row][col] == EMPTY {
+                for num in self.candidates[row][col] {
+                    positions_for_digit[num as usize].push(idx);
+                }
+            }
+        }
+        // Only digits appearing in two to four cells can be part of a hidden quad
+        let digits: Vec<usize> = (1..=9)
+            .filter(|&d| (2..=4).contains(&positions_for_digit[d].len()))
+            .collect();
+        for i in 0..digits.len() {
+            for j in (i + 1)..digits.len() {
+                for k in (j + 1)..digits.len() {
+                    for l in (k + 1)..digits.len() {
+                        let quad_digits = [
+                            digits[i] as u8,
+                            digits[j] as u8,
+                            digits[k] as u8,
+                            digits[l] as u8,
+                        ];
+                        let positions: HashSet<usize> = quad_digits
+                            .iter()
+                            .flat_map(|&d| positions_for_digit[d as usize].iter().cloned())
+                            .collect();
+                        if positions.len() != 4 {
+                            continue;
+                        }
+                        // Remove all other candidates from these cells
+                        for &idx in &positions {
+                            let (row, col) = cells[idx];
+                            for num in self.candidates[row][col] {
+                                if !quad_digits.contains(&num) {
+                                    result.candidates_about_to_be_removed.insert(Candidate {
+                                        row,
+                                        col,
+                                        num,
+                                    });
+                                }
+                            }
+                        }
+                        if result.will_remove_candidates() {
+                            for &idx in &positions {
+                                let (row, col) = cells[idx];
+                                for &num in &quad_digits {
+                                    if self.candidates[row][col].contains(num) {
+                                        result.candidates_affected.push(Candidate {
+                                            row,
+                                            col,
+                                            num,
+                                        });
+                                    }
+                                }
+                            }
+                            return result;
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    pub fn find_obvious_quad_in_rows(&self) -> RemovalResult {
+        for row in 0..9 {
+            let mut result =
+                self.find_obvious_quad_in_cells(&Self::get_unit_cells(&Unit::Row, row));
+            if result.will_remove_candidates() {
+                result.unit = Some(Unit::Row);
+                result.unit_index = Some(vec![row]);
+                return result;
+            }
+        }
+        RemovalResult::empty()
+    }
+
+    pub fn find_obvious_quad_in_cols(&self) -> RemovalResult {
+        for col in 0..9 {
+            let mut result =
+                self.find_obvious_quad_in_cells(&Self::get_unit_cells(&Unit::Column, col));
+            if result.will_remove_candidates() {
+                result.unit = Some(Unit::Column);
+                result.unit_index = Some(vec![col]);
+                return result;
+            }
+        }
+        RemovalResult::empty()
+    }
+
+    pub fn find_obvious_quad_in_boxes(&self) -> RemovalResult {
+        for box_idx in 0..9 {
+            let mut result =
+                self.find_obvious_quad_in_cells(&Self::get_unit_cells(&Unit::Box, box_idx));
+            if result.will_remove_candidates() {
+                result.unit = Some(Unit::Box);
+                result.unit_index = Some(vec![box_idx]);
+                return result;
+            }
+        }
+        RemovalResult::empty()
+    }
+
+    pub fn find_hidden_quad_in_rows(&self) -> RemovalResult {
+        for row in 0..9 {
+            let mut result = self.find_hidden_quad_in_cells(&Self::get_unit_cells(&Unit::Row, row));
+            if result.will_remove_candidates() {
+                result.unit = Some(Unit::Row);
+                result.unit_index = Some(vec![row]);
+                return result;
+            }
+        }
+        RemovalResult::empty()
+    }
+
+    pub fn find_hidden_quad_in_cols(&self) -> RemovalResult {
+        for col in 0..9 {
+            let mut result =
+                self.find_hidden_quad_in_cells(&Self::get_unit_cells(&Unit::Column, col));
+            if result.will_remove_candidates() {
+                result.unit = Some(Unit::Column);
+                result.unit_index = Some(vec![col]);
+                return result;
+            }
+        }
+        RemovalResult::empty()
+    }
+
+    pub fn find_hidden_quad_in_boxes(&self) -> RemovalResult {
+        for box_idx in 0..9 {
+            let mut result =
+                self.find_hidden_quad_in_cells(&Self::get_unit_cells(&Unit::Box, box_idx));
+            if result.will_remove_candidates() {
+                result.unit = Some(Unit::Box);
+                result.unit_index = Some(vec![box_idx]);
+                return result;
+            }
+        }
+        RemovalResult::empty()
+    }
+
+    /// Find and resolve obvious (naked) quads.
+    /// An obvious quad occurs when four cells of a row, column or box contain only
+    /// four different candidates. These can be removed from the other cells of the unit.
+    pub fn find_obvious_quad(&self) -> StrategyResult {
+        log::info!("Finding obvious quads in rows");
+        let result = self.find_obvious_quad_in_rows();
+        if result.will_remove_candidates() {
+            return StrategyResult {
+                strategy: Strategy::ObviousQuad,
+                removals: result,
+            };
+        }
+        log::info!("Finding obvious quads in columns");
+        let result = self.find_obvious_quad_in_cols();
+        if result.will_remove_candidates() {
+            return StrategyResult {
+                strategy: Strategy::ObviousQuad,
+                removals: result,
+            };
+        }
+        log::info!("Finding obvious quads in boxes");
+        let result = self.find_obvious_quad_in_boxes();
+        StrategyResult {
+            strategy: Strategy::ObviousQuad,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve hidden quads.
+    /// A hidden quad occurs when four digits can only go in the same four cells
+    /// of a row, column or box. All other candidates can be removed from these cells.
+    pub fn find_hidden_quad(&self) -> StrategyResult {
+        log::info!("Finding hidden quads in rows");
+        let result = self.find_hidden_quad_in_rows();
+        if result.will_remove_candidates() {
+            return StrategyResult {
+                strategy: Strategy::HiddenQuad,
+                removals: result,
+            };
+        }
+        log::info!("Finding hidden quads in columns");
+        let result = self.find_hidden_quad_in_cols();
+        if result.will_remove_candidates() {
+            return StrategyResult {
+                strategy: Strategy::HiddenQuad,
+                removals: result,
+            };
+        }
+        log::info!("Finding hidden quads in boxes");
+        let result = self.find_hidden_quad_in_boxes();
+        StrategyResult {
+            strategy: Strategy::HiddenQuad,
+            removals: result,
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use rate_my_sudoku::Sudoku;
+use clap::ValueEnum;
+use rate_my_sudoku::{SolverProfile, Sudoku};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
@@ -16,6 +17,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     let mut s0 = Sudoku::new();
     s0.set_board_string(&args[1])?;
+    if let Some(profile) = args.get(2) {
+        s0.config = SolverProfile::from_str(profile, true)?.into();
+    }
     let start = std::time::Instant::now();
     s0.solve_puzzle();
     let duration = start.elapsed();
@@ -0,0 +1,176 @@
+use crate::{Candidate, EMPTY, Strategy, StrategyResult, Sudoku};
+use std::fmt;
+
+/// The steps applied with one technique and the effort they took
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StrategyRating {
+    /// Number of applied steps
+    pub steps: usize,
+    /// Number of removed candidates, including the digits placed in solved cells
+    pub count: usize,
+    /// Effort of all steps
+    pub effort: i32,
+    /// Effort of the technique per removed candidate, without bonuses, as configured when
+    /// the last step was applied
+    pub unit_effort: i32,
+}
+
+/// Rating of one technique within a `RatingReport`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RatingReportEntry {
+    pub strategy: Strategy,
+    pub rating: StrategyRating,
+}
+
+/// The rating of a (partially) solved puzzle.
+///
+/// Every step applied with `Sudoku::apply` is rated exactly once, when it is applied. A step
+/// of technique `S` that removes `n` candidates from the board (solving a cell removes all
+/// of its candidates, including the placed digit) takes the effort
+///
+/// ```text
+/// effort(step) = n × (e(S) + b(step))
+/// ```
+///
+/// where `e(S)` is the effort of the technique according to the solver's `SolverConfig` at
+/// the time the step is applied, and `b(step)` the bonus for the length of a chain and the
+/// nesting depth of guesses. The effort of the puzzle is the average over all applied steps:
+///
+/// ```text
+/// effort = Σ effort(step) / Σ n
+/// ```
+///
+/// Undoing a step restores the rating from before the step.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RatingReport {
+    /// The techniques used, ordered by effort
+    pub strategies: Vec<RatingReportEntry>,
+    pub solved: bool,
+}
+
+impl RatingReport {
+    /// Number of removed candidates of all steps
+    pub fn count(&self) -> usize {
+        self.strategies.iter().map(|entry| entry.rating.count).sum()
+    }
+
+    /// Effort of all steps
+    pub fn total_effort(&self) -> i32 {
+        self.strategies
+            .iter()
+            .map(|entry| entry.rating.effort)
+            .sum()
+    }
+
+    /// Average effort per removed candidate: the difficulty of the puzzle.
+    /// 0 if no step was applied.
+    pub fn effort(&self) -> f64 {
+        match self.count() {
+            0 => 0.0,
+            count => self.total_effort() as f64 / count as f64,
+        }
+    }
+
+    /// The technique with the highest effort that was needed
+    pub fn hardest_strategy(&self) -> Option<Strategy> {
+        self.strategies.last().map(|entry| entry.strategy)
+    }
+}
+
+impl fmt::Display for RatingReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Rating:")?;
+        writeln!(f, "  Difficulty: {:.2}", self.effort())?;
+        writeln!(f, "  Total candidates removed: {}; by …", self.count())?;
+        for entry in &self.strategies {
+            writeln!(
+                f,
+                "  - {} ({}): {} in {} steps, effort {}",
+                entry.strategy,
+                entry.rating.unit_effort,
+                entry.rating.count,
+                entry.rating.steps,
+                entry.rating.effort
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Sudoku {
+    /// Effort of a step, see `RatingReport` for the formula. `None` if the effort of the
+    /// technique is unknown.
+    pub fn step_effort(&self, result: &StrategyResult) -> Option<i32> {
+        let count = self.step_count(result) as i32;
+        let bonus = self.chain_length_difficulty(result.removals.chain.len())
+            + self.trial_depth_difficulty(result.removals.trial_depth);
+        let unit_effort = self.strategy_effort(result.strategy)?;
+        Some(count * (unit_effort + bonus))
+    }
+
+    /// Number of candidates a step removes from the board, including the placed digit of a
+    /// solved cell. Candidates that are already gone don't count.
+    fn step_count(&self, result: &StrategyResult) -> usize {
+        let removals = &result.removals;
+        let removed = removals
+            .candidates_about_to_be_removed
+            .iter()
+            .filter(|candidate| {
+                self.candidates[candidate.row][candidate.col].contains(candidate.num)
+            })
+            .count();
+        let placed = removals.sets_cell.as_ref().is_some_and(|cell| {
+            self.board[cell.row][cell.col] == EMPTY
+                && !removals
+                    .candidates_about_to_be_removed
+                    .contains(&Candidate {
+                        row: cell.row,
+                        col: cell.col,
+                        num: cell.num,
+                    })
+        });
+        removed + usize::from(placed)
+    }
+
+    /// Add a step that is about to be applied to the rating, and return its effort. Steps of
+    /// techniques without a known effort are not rated.
+    pub(crate) fn rate_step(&mut self, result: &StrategyResult) -> i32 {
+        if result.strategy == Strategy::None {
+            return 0;
+        }
+        let (Some(effort), Some(unit_effort)) = (
+            self.step_effort(result),
+            self.strategy_effort(result.strategy),
+        ) else {
+            log::error!(
+                "Can't rate a step of {}: the technique isn't registered",
+                result.strategy
+            );
+            return 0;
+        };
+        let count = self.step_count(result);
+        let rating = self.rating.entry(result.strategy).or_default();
+        rating.steps += 1;
+        rating.count += count;
+        rating.effort += effort;
+        rating.unit_effort = unit_effort;
+        effort
+    }
+
+    /// The rating of all steps applied so far
+    pub fn rating_report(&self) -> RatingReport {
+        let mut strategies: Vec<RatingReportEntry> = self
+            .rating
+            .iter()
+            .map(|(&strategy, &rating)| RatingReportEntry { strategy, rating })
+            .collect();
+        strategies.sort_by(|a, b| {
+            (a.rating.unit_effort, a.strategy.to_string())
+                .cmp(&(b.rating.unit_effort, b.strategy.to_string()))
+        });
+        RatingReport {
+            strategies,
+            solved: self.is_solved(),
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{Strategy, StrategyResult, Sudoku};
+use std::fmt;
+use std::sync::{Arc, LazyLock};
+
+/// A solving technique the human-like solver can use. Implement this trait to add your
+/// own techniques and register them with a `StrategyRegistry`.
+pub trait SolvingStrategy: Send + Sync {
+    /// The identifier reported in results and ratings; use `Strategy::Custom` for your own
+    /// techniques
+    fn strategy(&self) -> Strategy;
+
+    /// Human-readable name of the technique
+    fn name(&self) -> &str;
+
+    /// Effort of removing one candidate with this technique
+    fn effort(&self) -> i32;
+
+    /// Find the first occurrence of the technique on the board
+    fn find_one(&self, sudoku: &Sudoku) -> StrategyResult;
+
+    /// Find the occurrences of the technique on the board. The default implementation
+    /// returns the first occurrence only, and the built-in techniques keep it; override it
+    /// if your technique can list all of its occurrences.
+    fn find_all(&self, sudoku: &Sudoku) -> Vec<StrategyResult> {
+        let result = self.find_one(sudoku);
+        if result.removals.will_remove_candidates() {
+            vec![result]
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Whether the technique is only valid if the puzzle has exactly one solution
+    fn requires_unique_solution(&self) -> bool {
+        false
+    }
+}
+
+/// Finder function of a technique implemented by this crate
+type StrategyFinder = fn(&Sudoku) -> StrategyResult;
+
+/// A technique implemented by this crate. Its finder stops at the first occurrence, so
+/// `find_all` reports at most one result.
+struct BuiltinStrategy {
+    strategy: Strategy,
+    find: StrategyFinder,
+}
+
+impl SolvingStrategy for BuiltinStrategy {
+    fn strategy(&self) -> Strategy {
+        self.strategy
+    }
+
+    fn name(&self) -> &str {
+        self.strategy.to_string()
+    }
+
+    fn effort(&self) -> i32 {
+        self.strategy
+            .builtin_effort()
+            .expect("built-in techniques have an effort")
+    }
+
+    fn find_one(&self, sudoku: &Sudoku) -> StrategyResult {
+        (self.find)(sudoku)
+    }
+
+    fn requires_unique_solution(&self) -> bool {
+        self.strategy.requires_unique_solution()
+    }
+}
+
+/// The techniques available to the human-like solver, ordered by effort
+pub struct StrategyRegistry {
+    strategies: Vec<Box<dyn SolvingStrategy>>,
+}
+
+static BUILTIN_REGISTRY: LazyLock<Arc<StrategyRegistry>> =
+    LazyLock::new(|| Arc::new(StrategyRegistry::builtin()));
+
+impl StrategyRegistry {
+    /// A registry without any techniques
+    pub fn empty() -> Self {
+        StrategyRegistry {
+            strategies: Vec::new(),
+        }
+    }
+
+    /// A registry holding all techniques implemented by this crate
+    pub fn builtin() -> Self {
+        let builtins: Vec<(Strategy, StrategyFinder)> = vec![
+            (Strategy::LastDigit, Sudoku::find_last_digit),
+            (Strategy::ObviousSingle, Sudoku::find_obvious_single),
+            (Strategy::HiddenSingle, Sudoku::find_hidden_single),
+            (Strategy::LockedPair, Sudoku::find_locked_pair),
+            (Strategy::PointingPair, Sudoku::find_pointing_pair),
+            (Strategy::ClaimingPair, Sudoku::find_claiming_pair),
+            (Strategy::ObviousPair, Sudoku::find_obvious_pair),
+            (Strategy::HiddenPair, Sudoku::find_hidden_pair),
+            (Strategy::ObviousTriplet, Sudoku::find_obvious_triplet),
+            (Strategy::HiddenTriplet, Sudoku::find_hidden_triplet),
+            (Strategy::ObviousQuad, Sudoku::find_obvious_quad),
+            (Strategy::HiddenQuad, Sudoku::find_hidden_quad),
+            (Strategy::Skyscraper, Sudoku::find_skyscraper),
+            (Strategy::TurbotFish, Sudoku::find_turbot_fish),
+            (Strategy::TwoStringKite, Sudoku::find_two_string_kite),
+            (Strategy::EmptyRectangle, Sudoku::find_empty_rectangle),
+            (
+                Strategy::UniqueRectangleType1,
+                Sudoku::find_unique_rectangle_type_1,
+            ),
+            (
+                Strategy::UniqueRectangleType2,
+                Sudoku::find_unique_rectangle_type_2,
+            ),
+            (
+                Strategy::UniqueRectangleType3,
+                Sudoku::find_unique_rectangle_type_3,
+            ),
+            (
+                Strategy::UniqueRectangleType4,
+                Sudoku::find_unique_rectangle_type_4,
+            ),
+            (
+                Strategy::UniqueRectangleType5,
+                Sudoku::find_unique_rectangle_type_5,
+            ),
+            (
+                Strategy::UniqueRectangleType6,
+                Sudoku::find_unique_rectangle_type_6,
+            ),
+            (
+                Strategy::HiddenUniqueRectangle,
+                Sudoku::find_hidden_unique_rectangle,
+            ),
+            (Strategy::BugPlusOne, Sudoku::find_bug_plus_one),
+            (Strategy::SimpleColorsTrap, Sudoku::find_simple_colors_trap),
+            (Strategy::SimpleColorsWrap, Sudoku::find_simple_colors_wrap),
+            (Strategy::MultiColors, Sudoku::find_multi_colors),
+            (Strategy::Medusa3D, Sudoku::find_3d_medusa),
+            (Strategy::XChain, Sudoku::find_x_chain),
+            (Strategy::XYChain, Sudoku::find_xy_chain),
+            (
+                Strategy::ContinuousNiceLoop,
+                Sudoku::find_continuous_nice_loop,
+            ),
+            (
+                Strategy::DiscontinuousNiceLoop,
+                Sudoku::find_discontinuous_nice_loop,
+            ),
+            (Strategy::Aic, Sudoku::find_aic),
+            (
+                Strategy::GroupedContinuousNiceLoop,
+                Sudoku::find_grouped_continuous_nice_loop,
+            ),
+            (
+                Strategy::GroupedDiscontinuousNiceLoop,
+                Sudoku::find_grouped_discontinuous_nice_loop,
+            ),
+            (Strategy::GroupedAic, Sudoku::find_grouped_aic),
+            (Strategy::AlsXz, Sudoku::find_als_xz),
+            (Strategy::AlsXyWing, Sudoku::find_als_xy_wing),
+            (Strategy::DeathBlossom, Sudoku::find_death_blossom),
+            (Strategy::SueDeCoq, Sudoku::find_sue_de_coq),
+            (Strategy::CellForcingChain, Sudoku::find_cell_forcing_chain),
+            (Strategy::UnitForcingChain, Sudoku::find_unit_forcing_chain),
+            (
+                Strategy::ContradictionForcingNet,
+                Sudoku::find_contradiction_forcing_net,
+            ),
+            (Strategy::PatternOverlay, Sudoku::find_pattern_overlay),
+            (Strategy::BowmansBingo, Sudoku::find_bowmans_bingo),
+            (Strategy::WWing, Sudoku::find_wwing),
+            (Strategy::XWing, Sudoku::find_xwing),
+            (Strategy::Swordfish, Sudoku::find_swordfish),
+            (Strategy::Jellyfish, Sudoku::find_jellyfish),
+            (Strategy::FinnedXWing, Sudoku::find_finned_xwing),
+            (Strategy::FinnedSwordfish, Sudoku::find_finned_swordfish),
+            (Strategy::FinnedJellyfish, Sudoku::find_finned_jellyfish),
+            (Strategy::SashimiXWing, Sudoku::find_sashimi_xwing),
+            (Strategy::SashimiSwordfish, Sudoku::find_sashimi_swordfish),
+            (Strategy::SashimiJellyfish, Sudoku::find_sashimi_jellyfish),
+            (Strategy::XYWing, Sudoku::find_xywing),
+            (Strategy::XYZWing, Sudoku::find_xyzwing),
+        ];
+        let mut registry = StrategyRegistry::empty();
+        for (strategy, find) in builtins {
+            registry.register(BuiltinStrategy { strategy, find });
+        }
+        registry
+    }
+
+    /// The shared registry of all built-in techniques
+    pub(crate) fn shared_builtin() -> Arc<StrategyRegistry> {
+        BUILTIN_REGISTRY.clone()
+    }
+
+    /// Add a technique. It is tried after all registered techniques with the same or a
+    /// lower effort, and before the ones with a higher effort.
+    pub fn register(&mut self, strategy: impl SolvingStrategy + 'static) {
+        let index = self
+            .strategies
+            .partition_point(|other| other.effort() <= strategy.effort());
+        self.strategies.insert(index, Box::new(strategy));
+    }
+
+    /// Remove a technique, e.g. to replace a built-in one with your own implementation
+    pub fn unregister(&mut self, strategy: Strategy) {
+        self.strategies.retain(|other| other.strategy() != strategy);
+    }
+
+    /// Look up the technique reporting the given identifier
+    pub fn get(&self, strategy: Strategy) -> Option<&dyn SolvingStrategy> {
+        self.strategies
+            .iter()
+            .find(|other| other.strategy() == strategy)
+            .map(|other| other.as_ref())
+    }
+
+    /// Effort of the technique reporting the given identifier, falling back to the
+    /// built-in value for built-in techniques that aren't registered. `None` for custom
+    /// techniques that aren't registered.
+    pub fn effort(&self, strategy: Strategy) -> Option<i32> {
+        match self.get(strategy) {
+            Some(other) => Some(other.effort()),
+            None => strategy.builtin_effort(),
+        }
+    }
+
+    /// Iterate over the techniques in the order they are tried
+    pub fn iter(&self) -> impl Iterator<Item = &dyn SolvingStrategy> {
+        self.strategies.iter().map(|strategy| strategy.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.strategies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strategies.is_empty()
+    }
+}
+
+impl Default for StrategyRegistry {
+    fn default() -> Self {
+        StrategyRegistry::builtin()
+    }
+}
+
+impl fmt::Debug for StrategyRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.strategies.iter().map(|strategy| strategy.name()))
+            .finish()
+    }
+}
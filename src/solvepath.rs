@@ -0,0 +1,199 @@
+use crate::{Candidate, Cell, RemovalResult, Strategy, StrategyResult, Sudoku, SudokuError, Unit};
+use serde_json::json;
+use std::fmt;
+
+/// One step of the human-like solver, as it was applied to the board
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveStep {
+    pub strategy: Strategy,
+    pub unit: Option<Unit>,
+    pub unit_index: Option<Vec<usize>>,
+    /// The cell solved by the step, if any
+    pub sets_cell: Option<Cell>,
+    /// Candidates removed by the step, ordered by row, column and digit
+    pub candidates_removed: Vec<Candidate>,
+    /// Effort the step added to the rating (see `RatingReport`)
+    pub effort: i32,
+    /// Hash of the board and all candidates before the step (see `Sudoku::snapshot_hash`)
+    pub hash_before: u64,
+    /// Hash of the board and all candidates after the step
+    pub hash_after: u64,
+}
+
+impl SolveStep {
+    /// Apply a step found by `next_step` and record it
+    pub(crate) fn record(sudoku: &mut Sudoku, result: &StrategyResult) -> Self {
+        let mut candidates_removed: Vec<Candidate> = result
+            .removals
+            .candidates_about_to_be_removed
+            .iter()
+            .cloned()
+            .collect();
+        candidates_removed.sort_by_key(|c| (c.row, c.col, c.num));
+        let hash_before = sudoku.snapshot_hash();
+        let effort = sudoku.apply(result).effort;
+        SolveStep {
+            strategy: result.strategy,
+            unit: result.removals.unit.clone(),
+            unit_index: result.removals.unit_index.clone(),
+            sets_cell: result.removals.sets_cell.clone(),
+            candidates_removed,
+            effort,
+            hash_before,
+            hash_after: sudoku.snapshot_hash(),
+        }
+    }
+
+    /// The step as a result that can be passed to `Sudoku::apply`
+    pub fn to_strategy_result(&self) -> StrategyResult {
+        let mut removals = RemovalResult::empty();
+        removals.sets_cell = self.sets_cell.clone();
+        removals.unit = self.unit.clone();
+        removals.unit_index = self.unit_index.clone();
+        removals
+            .candidates_about_to_be_removed
+            .extend(self.candidates_removed.iter().cloned());
+        StrategyResult {
+            strategy: self.strategy,
+            removals,
+        }
+    }
+
+    fn to_json_value(&self) -> serde_json::Value {
+        json!({
+            "strategy": self.strategy.to_string(),
+            "unit": self.unit.as_ref().map(|unit| unit.to_string()),
+            "unit_index": self.unit_index,
+            "sets_cell": self.sets_cell.as_ref().map(|cell| [cell.row, cell.col, cell.num as usize]),
+            "candidates_removed": self
+                .candidates_removed
+                .iter()
+                .map(|c| [c.row, c.col, c.num as usize])
+                .collect::<Vec<_>>(),
+            "effort": self.effort,
+            "hash_before": format!("{:016x}", self.hash_before),
+            "hash_after": format!("{:016x}", self.hash_after),
+        })
+    }
+}
+
+impl fmt::Display for SolveStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.strategy)?;
+        if let (Some(unit), Some(index)) = (&self.unit, &self.unit_index) {
+            write!(f, " in {} {:?}", unit, index)?;
+        }
+        if let Some(cell) = &self.sets_cell {
+            write!(f, ": ({}, {}) = {}", cell.row, cell.col, cell.num)?;
+        } else {
+            let removed: Vec<String> = self
+                .candidates_removed
+                .iter()
+                .map(|c| format!("({}, {}) ≠ {}", c.row, c.col, c.num))
+                .collect();
+            write!(f, ": {}", removed.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The ordered steps the human-like solver took from the puzzle to the solution, or to the
+/// point where it got stuck
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SolvePath {
+    /// The puzzle in single-line format
+    pub puzzle: String,
+    pub steps: Vec<SolveStep>,
+    /// Whether the steps lead to the solution
+    pub solved: bool,
+}
+
+impl SolvePath {
+    pub fn new(puzzle: String) -> Self {
+        SolvePath {
+            puzzle,
+            steps: Vec::new(),
+            solved: false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, SolveStep> {
+        self.steps.iter()
+    }
+
+    /// Apply the first `count` steps to the puzzle. Fails if the board doesn't match the
+    /// recorded snapshot before or after a step, i.e. the path doesn't belong to the puzzle.
+    pub fn replay(&self, count: usize) -> Result<Sudoku, SudokuError> {
+        let mut sudoku = Sudoku::from_string(&self.puzzle)?;
+        sudoku.calc_candidates();
+        for (index, step) in self.steps.iter().take(count).enumerate() {
+            if sudoku.snapshot_hash() != step.hash_before {
+                return Err(SudokuError {
+                    message: format!("Board doesn't match the path before step {}", index),
+                });
+            }
+            sudoku.apply(&step.to_strategy_result());
+            if sudoku.snapshot_hash() != step.hash_after {
+                return Err(SudokuError {
+                    message: format!("Board doesn't match the path after step {}", index),
+                });
+            }
+        }
+        Ok(sudoku)
+    }
+
+    /// Serialize the path to JSON
+    pub fn to_json(&self) -> String {
+        json!({
+            "puzzle": self.puzzle,
+            "solved": self.solved,
+            "steps": self.steps.iter().map(SolveStep::to_json_value).collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+}
+
+impl<'a> IntoIterator for &'a SolvePath {
+    type Item = &'a SolveStep;
+    type IntoIter = std::slice::Iter<'a, SolveStep>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.steps.iter()
+    }
+}
+
+impl fmt::Display for SolvePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, step) in self.steps.iter().enumerate() {
+            writeln!(f, "{:3}. {}", index + 1, step)?;
+        }
+        Ok(())
+    }
+}
+
+impl Sudoku {
+    /// Hash of the board and all candidates, to recognize the state of a puzzle. FNV-1a over
+    /// the 81 digits and the 81 candidate masks (little endian), so it stays the same across
+    /// platforms and Rust releases.
+    pub fn snapshot_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+        let digits = self.board.iter().flatten().cloned();
+        let masks = self
+            .candidates
+            .iter()
+            .flatten()
+            .flat_map(|candidates| candidates.bits().to_le_bytes());
+        digits.chain(masks).fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        })
+    }
+}
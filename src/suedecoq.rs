@@ -0,0 +1,173 @@
+use crate::{
+    Candidate, CandidateSet, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, Unit,
+};
+
+/// A set of cells together with all of their candidates
+type CellSet = (Vec<(usize, usize)>, CandidateSet);
+
+impl Sudoku {
+    /// Collect the candidates of all given cells
+    fn candidates_of_cells(&self, cells: &[(usize, usize)]) -> CandidateSet {
+        cells
+            .iter()
+            .flat_map(|&(row, col)| self.candidates[row][col].iter())
+            .collect()
+    }
+
+    /// Remove every digit of `nums` from the cells of `unit` outside `keep`
+    fn remove_from_unit_except(
+        &self,
+        unit: &Unit,
+        index: usize,
+        keep: &[(usize, usize)],
+        nums: &CandidateSet,
+        result: &mut RemovalResult,
+    ) {
+        for (row, col) in Self::get_unit_cells(unit, index) {
+            if keep.contains(&(row, col)) {
+                continue;
+            }
+            for num in nums {
+                if self.candidates[row][col].contains(num) {
+                    result
+                        .candidates_about_to_be_removed
+                        .insert(Candidate { row, col, num });
+                }
+            }
+        }
+    }
+
+    /// Report the candidates of the cell sets as affected, colored by set
+    fn push_colored_cells(&self, sets: &[&[(usize, usize)]], result: &mut RemovalResult) {
+        for (color, cells) in sets.iter().enumerate() {
+            for &(row, col) in cells.iter() {
+                for num in self.candidates[row][col] {
+                    let candidate = Candidate { row, col, num };
+                    result.candidates_affected.push(candidate);
+                    result.colored_candidates.push((candidate, color));
+                }
+            }
+        }
+    }
+
+    /// Subsets of the cells that share at least one candidate with `nums`, with their candidates
+    fn sue_de_coq_subsets(&self, cells: &[(usize, usize)], nums: &CandidateSet) -> Vec<CellSet> {
+        let cells: Vec<(usize, usize)> = cells
+            .iter()
+            .filter(|&&(row, col)| !self.candidates[row][col].is_disjoint(nums))
+            .cloned()
+            .collect();
+        let mut subsets = Vec::new();
+        for size in 1..=cells.len() {
+            for subset in Self::combinations(&cells, size) {
+                let subset_nums = self.candidates_of_cells(&subset);
+                subsets.push((subset, subset_nums));
+            }
+        }
+        subsets
+    }
+
+    /// Find and resolve Sue de Coq (two-sector disjoint subsets).
+    /// Two or more cells of a box/line intersection hold at least two more candidates than
+    /// cells. Together with cells of the line and cells of the box outside the intersection
+    /// whose candidates don't overlap, they form a locked set: as many digits as cells, each of
+    /// them exactly once. So the line cells' digits can be removed from the rest of the line,
+    /// the box cells' digits from the rest of the box, and any remaining digits of the
+    /// intersection from both.
+    pub fn find_sue_de_coq(&self) -> StrategyResult {
+        log::info!("Finding Sue de Coq");
+        let mut result = RemovalResult::empty();
+        for box_index in 0..9 {
+            let (start_row, start_col) = Self::get_box_start_from_index(box_index);
+            let box_cells: Vec<(usize, usize)> = Self::get_unit_cells(&Unit::Box, box_index)
+                .into_iter()
+                .filter(|&(row, col)| self.board[row][col] == EMPTY)
+                .collect();
+            let lines = (0..3)
+                .map(|i| (Unit::Row, start_row + i))
+                .chain((0..3).map(|i| (Unit::Column, start_col + i)));
+            for (line, line_index) in lines {
+                let line_cells: Vec<(usize, usize)> = Self::get_unit_cells(&line, line_index)
+                    .into_iter()
+                    .filter(|&(row, col)| self.board[row][col] == EMPTY)
+                    .collect();
+                let intersection: Vec<(usize, usize)> = line_cells
+                    .iter()
+                    .filter(|cell| box_cells.contains(cell))
+                    .cloned()
+                    .collect();
+                let line_rest: Vec<(usize, usize)> = line_cells
+                    .iter()
+                    .filter(|cell| !intersection.contains(cell))
+                    .cloned()
+                    .collect();
+                let box_rest: Vec<(usize, usize)> = box_cells
+                    .iter()
+                    .filter(|cell| !intersection.contains(cell))
+                    .cloned()
+                    .collect();
+                for size in 2..=intersection.len() {
+                    for cells in Self::combinations(&intersection, size) {
+                        let nums = self.candidates_of_cells(&cells);
+                        if nums.len() < size + 2 {
+                            continue;
+                        }
+                        let line_subsets = self.sue_de_coq_subsets(&line_rest, &nums);
+                        let box_subsets = self.sue_de_coq_subsets(&box_rest, &nums);
+                        for (line_set, line_nums) in &line_subsets {
+                            for (box_set, box_nums) in &box_subsets {
+                                if !line_nums.is_disjoint(box_nums) {
+                                    continue;
+                                }
+                                let mut all_nums = nums;
+                                all_nums.extend(line_nums);
+                                all_nums.extend(box_nums);
+                                if all_nums.len() != size + line_set.len() + box_set.len() {
+                                    continue;
+                                }
+                                // Digits only in the intersection are locked in both units
+                                let rest_nums = nums.difference(line_nums).difference(box_nums);
+                                let in_line: Vec<(usize, usize)> =
+                                    cells.iter().chain(line_set).cloned().collect();
+                                let in_box: Vec<(usize, usize)> =
+                                    cells.iter().chain(box_set).cloned().collect();
+                                let line_locked: CandidateSet = line_nums.union(&rest_nums);
+                                let box_locked: CandidateSet = box_nums.union(&rest_nums);
+                                self.remove_from_unit_except(
+                                    &line,
+                                    line_index,
+                                    &in_line,
+                                    &line_locked,
+                                    &mut result,
+                                );
+                                self.remove_from_unit_except(
+                                    &Unit::Box,
+                                    box_index,
+                                    &in_box,
+                                    &box_locked,
+                                    &mut result,
+                                );
+                                if result.will_remove_candidates() {
+                                    self.push_colored_cells(
+                                        &[&cells, line_set, box_set],
+                                        &mut result,
+                                    );
+                                    result.unit = Some(Unit::Box);
+                                    result.unit_index = Some(vec![box_index]);
+                                    return StrategyResult {
+                                        strategy: Strategy::SueDeCoq,
+                                        removals: result,
+                                    };
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::SueDeCoq,
+            removals: result,
+        }
+    }
+}
@@ -2,83 +2,62 @@ use crate::{Candidate, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, U
 use std::collections::HashSet;
 
 impl Sudoku {
-    pub fn find_hidden_triplet_in_rows(&self) -> RemovalResult {
+    /// Find a hidden triplet among the given cells of a unit: three digits that,
+    /// within the unit, only appear as candidates in the same three cells.
+    /// All other candidates can be removed from these cells.
+    fn find_hidden_triplet_in_cells(&self, cells: &[(usize, usize)]) -> RemovalResult {
         let mut result = RemovalResult::empty();
-        for row in 0..9 {
-            // For each position in the row, track which positions have each digit as a candidate
-            let mut positions_for_digit: [Vec<usize>; 10] = Default::default();
-
-            // Fill the positions array
-            for col in 0..9 {
-                if self.board[row][col] == EMPTY {
-                    for &num in &self.candidates[row][col] {
-                        positions_for_digit[num as usize].push(col);
-                    }
+        // For each digit, track which of the cells have it as a candidate
+        let mut positions_for_digit: [Vec<usize>; 10] = Default::default();
+        for (idx, &(row, col)) in cells.iter().enumerate() {
+            if self.board[row][col] == EMPTY {
+                for num in self.candidates[row][col] {
+                    positions_for_digit[num as usize].push(idx);
                 }
             }
-
-            // Look for triplets: digits that appear as candidates in exactly 3 cells
-            for d1 in 1..=9 {
-                if positions_for_digit[d1].len() != 3 {
-                    continue;
-                }
-                for d2 in (d1 + 1)..=9 {
-                    if positions_for_digit[d2].len() != 3 {
+        }
+        // Only digits appearing in two or three cells can be part of a hidden triplet
+        let digits: Vec<usize> = (1..=9)
+            .filter(|&d| (2..=3).contains(&positions_for_digit[d].len()))
+            .collect();
+        for (i, &d1) in digits.iter().enumerate() {
+            for (j, &d2) in digits.iter().enumerate().skip(i + 1) {
+                for &d3 in digits.iter().skip(j + 1) {
+                    let positions: HashSet<usize> = positions_for_digit[d1]
+                        .iter()
+                        .chain(&positions_for_digit[d2])
+                        .chain(&positions_for_digit[d3])
+                        .cloned()
+                        .collect();
+                    // If the three digits are confined to three cells, we have a hidden triplet
+                    if positions.len() != 3 {
                         continue;
                     }
-                    for d3 in (d2 + 1)..=9 {
-                        if positions_for_digit[d3].len() != 3 {
-                            continue;
-                        }
-
-                        // Check if these three digits appear in the same 3 cells
-                        let pos1: HashSet<_> = positions_for_digit[d1].iter().cloned().collect();
-                        let pos2: HashSet<_> = positions_for_digit[d2].iter().cloned().collect();
-                        let pos3: HashSet<_> = positions_for_digit[d3].iter().cloned().collect();
-
-                        let intersection: Vec<_> = pos1
-                            .intersection(&pos2)
-                            .cloned()
-                            .collect::<HashSet<_>>()
-                            .intersection(&pos3)
-                            .cloned()
-                            .collect();
-
-                        // If all three digits appear in the same 3 cells, we have a hidden triplet
-                        if intersection.len() == 3 {
-                            let triplet_cols = intersection;
-                            let triplet_digits = [d1 as u8, d2 as u8, d3 as u8];
-
-                            // Mark these candidates as affected
-                            for &col in &triplet_cols {
-                                for &digit in &triplet_digits {
-                                    result.candidates_affected.push(Candidate {
-                                        row,
-                                        col,
-                                        num: digit,
-                                    });
-                                }
+                    let triplet_digits = [d1 as u8, d2 as u8, d3 as u8];
+                    // Remove all other candidates from these cells
+                    for &idx in &positions {
+                        let (row, col) = cells[idx];
+                        for num in self.candidates[row][col] {
+                            if !triplet_digits.contains(&num) {
+                                result.candidates_about_to_be_removed.insert(Candidate {
+                                    row,
+                                    col,
+                                    num,
+                                });
                             }
-
-                            // Remove all other candidates from these cells
-                            for &col in &triplet_cols {
-                                for &num in &self.candidates[row][col] {
-                                    if !triplet_digits.contains(&num) {
-                                        result.candidates_about_to_be_removed.insert(Candidate {
-                                            row,
-                                            col,
-                                            num,
-                                        });
-                                    }
+                        }
+                    }
+                    if result.will_remove_candidates() {
+                        // Mark the triplet candidates as affected
+                        for &idx in &positions {
+                            let (row, col) = cells[idx];
+                            for &num in &triplet_digits {
+                                if self.candidates[row][col].contains(num) {
+                                    result.candidates_affected.push(Candidate { row, col, num });
                                 }
                             }
-
-                            if result.will_remove_candidates() {
-                                result.unit = Some(Unit::Row);
-                                result.unit_index = Some(vec![row]);
-                                return result;
-                            }
                         }
+                        return result;
                     }
                 }
             }
@@ -86,6 +65,73 @@ impl Sudoku {
         result
     }
 
+    pub fn find_hidden_triplet_in_rows(&self) -> RemovalResult {
+        for row in 0..9 {
+            let cells = Self::get_unit_cells(&Unit::Row, row);
+            let mut result = self.find_hidden_triplet_in_cells(&cells);
+            if result.will_remove_candidates() {
+                result.unit = Some(Unit::Row);
+                result.unit_index = Some(vec![row]);
+                return result;
+            }
+        }
+        RemovalResult::empty()
+    }
+
+    pub fn find_hidden_triplet_in_cols(&self) -> RemovalResult {
+        for col in 0..9 {
+            let cells = Self::get_unit_cells(&Unit::Column, col);
+            let mut result = self.find_hidden_triplet_in_cells(&cells);
+            if result.will_remove_candidates() {
+                result.unit = Some(Unit::Column);
+                result.unit_index = Some(vec![col]);
+                return result;
+            }
+        }
+        RemovalResult::empty()
+    }
+
+    pub fn find_hidden_triplet_in_boxes(&self) -> RemovalResult {
+        for box_idx in 0..9 {
+            let cells = Self::get_unit_cells(&Unit::Box, box_idx);
+            let mut result = self.find_hidden_triplet_in_cells(&cells);
+            if result.will_remove_candidates() {
+                result.unit = Some(Unit::Box);
+                result.unit_index = Some(vec![box_idx]);
+                return result;
+            }
+        }
+        RemovalResult::empty()
+    }
+
+    /// Find and resolve hidden triplets.
+    /// A hidden triplet occurs when three digits can only go in the same three cells
+    /// of a row, column or box. All other candidates can be removed from these cells.
+    pub fn find_hidden_triplet(&self) -> StrategyResult {
+        log::info!("Finding hidden triplets in rows");
+        let result = self.find_hidden_triplet_in_rows();
+        if result.will_remove_candidates() {
+            return StrategyResult {
+                strategy: Strategy::HiddenTriplet,
+                removals: result,
+            };
+        }
+        log::info!("Finding hidden triplets in columns");
+        let result = self.find_hidden_triplet_in_cols();
+        if result.will_remove_candidates() {
+            return StrategyResult {
+                strategy: Strategy::HiddenTriplet,
+                removals: result,
+            };
+        }
+        log::info!("Finding hidden triplets in boxes");
+        let result = self.find_hidden_triplet_in_boxes();
+        StrategyResult {
+            strategy: Strategy::HiddenTriplet,
+            removals: result,
+        }
+    }
+
     pub fn find_obvious_triplet_in_rows(&self) -> RemovalResult {
         let mut result = RemovalResult::empty();
         for row in 0..9 {
@@ -112,13 +158,9 @@ impl Sudoku {
                             continue;
                         }
                         // Combine candidates from all three cells
-                        let combined_candidates: HashSet<u8> = self.candidates[row][col1]
+                        let combined_candidates = self.candidates[row][col1]
                             .union(&self.candidates[row][col2])
-                            .cloned()
-                            .collect::<HashSet<u8>>()
-                            .union(&self.candidates[row][col3])
-                            .cloned()
-                            .collect();
+                            .union(&self.candidates[row][col3]);
                         // If we have exactly 3 unique candidates across these cells, we have a naked triplet
                         if combined_candidates.len() != 3 {
                             continue;
@@ -131,8 +173,8 @@ impl Sudoku {
                             .extend(triplet_cols.iter().flat_map(|&col| {
                                 combined_candidates
                                     .iter()
-                                    .filter(move |&&num| self.candidates[row][col].contains(&num))
-                                    .map(move |&num| Candidate { row, col, num })
+                                    .filter(move |&num| self.candidates[row][col].contains(num))
+                                    .map(move |num| Candidate { row, col, num })
                             }));
                         // Remove these candidates from other cells in the same row
                         for col in 0..9 {
@@ -141,8 +183,8 @@ impl Sudoku {
                             }
                             combined_candidates
                                 .iter()
-                                .filter(|&&num| self.candidates[row][col].contains(&num))
-                                .for_each(|&num| {
+                                .filter(|&num| self.candidates[row][col].contains(num))
+                                .for_each(|num| {
                                     result.candidates_about_to_be_removed.insert(Candidate {
                                         row,
                                         col,
@@ -188,13 +230,9 @@ impl Sudoku {
                             continue;
                         }
                         // Combine candidates from all three cells
-                        let combined_candidates: HashSet<u8> = self.candidates[row1][col]
+                        let combined_candidates = self.candidates[row1][col]
                             .union(&self.candidates[row2][col])
-                            .cloned()
-                            .collect::<HashSet<u8>>()
-                            .union(&self.candidates[row3][col])
-                            .cloned()
-                            .collect();
+                            .union(&self.candidates[row3][col]);
                         // If we have exactly 3 unique candidates across these cells, we have a naked triplet
                         if combined_candidates.len() != 3 {
                             continue;
@@ -207,8 +245,8 @@ impl Sudoku {
                             .extend(triplet_rows.iter().flat_map(|&row| {
                                 combined_candidates
                                     .iter()
-                                    .filter(move |&&num| self.candidates[row][col].contains(&num))
-                                    .map(move |&num| Candidate { row, col, num })
+                                    .filter(move |&num| self.candidates[row][col].contains(num))
+                                    .map(move |num| Candidate { row, col, num })
                             }));
                         // Remove these candidates from other cells in the same column
                         for row in 0..9 {
@@ -217,8 +255,8 @@ impl Sudoku {
                             }
                             combined_candidates
                                 .iter()
-                                .filter(|&&num| self.candidates[row][col].contains(&num))
-                                .for_each(|&num| {
+                                .filter(|&num| self.candidates[row][col].contains(num))
+                                .for_each(|num| {
                                     result.candidates_about_to_be_removed.insert(Candidate {
                                         row,
                                         col,
@@ -266,13 +304,7 @@ impl Sudoku {
                         cells_with_candidates.iter().enumerate().skip(j + 1)
                     {
                         // Combine candidates from all three cells
-                        let combined_candidates: HashSet<u8> = cands1
-                            .union(cands2)
-                            .cloned()
-                            .collect::<HashSet<u8>>()
-                            .union(cands3)
-                            .cloned()
-                            .collect();
+                        let combined_candidates = cands1.union(cands2).union(cands3);
                         // If we have exactly 3 unique candidates across these cells, we have a naked triplet
                         if combined_candidates.len() != 3 {
                             continue;
@@ -285,8 +317,8 @@ impl Sudoku {
                             .extend(triplet_cells.iter().flat_map(|&(row, col)| {
                                 combined_candidates
                                     .iter()
-                                    .filter(move |&&num| self.candidates[row][col].contains(&num))
-                                    .map(move |&num| Candidate { row, col, num })
+                                    .filter(move |&num| self.candidates[row][col].contains(num))
+                                    .map(move |num| Candidate { row, col, num })
                             }));
                         // Remove these candidates from other cells in the same box
                         for r in 0..3 {
@@ -297,8 +329,8 @@ impl Sudoku {
                                 if triplet_cells.contains(&cell) {
                                     continue; // Skip the triplet cells
                                 }
-                                for &num in &combined_candidates {
-                                    if self.candidates[row][col].contains(&num) {
+                                for num in &combined_candidates {
+                                    if self.candidates[row][col].contains(num) {
                                         result.candidates_about_to_be_removed.insert(Candidate {
                                             row,
                                             col,
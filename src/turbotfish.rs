@@ -0,0 +1,90 @@
+use crate::{Candidate, RemovalResult, Strategy, StrategyResult, Sudoku, Unit};
+
+impl Sudoku {
+    /// Find a Turbot Fish shaped pattern for a single digit: two strong links that are
+    /// connected by a weak link, i.e. one end of the first link sees one end of the
+    /// second. One of the two remaining ends has to be true, so the digit can be removed
+    /// from all cells that see both of them. `accept` decides which combinations of link
+    /// units and connecting ends are reported.
+    pub(crate) fn find_two_linked_strong_links(
+        &self,
+        accept: fn(&Unit, &Unit, &Candidate, &Candidate) -> bool,
+    ) -> RemovalResult {
+        let mut result = RemovalResult::empty();
+        for num in 1..=9 {
+            let links = self.find_strong_links(num);
+            for i in 0..links.len() {
+                for j in (i + 1)..links.len() {
+                    let (link1, unit1) = &links[i];
+                    let (link2, unit2) = &links[j];
+                    for (end1, connect1) in [(link1.base, link1.top), (link1.top, link1.base)] {
+                        for (end2, connect2) in [(link2.base, link2.top), (link2.top, link2.base)] {
+                            let cells = [end1, connect1, connect2, end2]
+                                .map(|candidate| (candidate.row, candidate.col));
+                            // All four cells have to be different
+                            if (0..4).any(|a| (a + 1..4).any(|b| cells[a] == cells[b])) {
+                                continue;
+                            }
+                            if !Self::cells_see_each_other(cells[1], cells[2])
+                                || !accept(unit1, unit2, &connect1, &connect2)
+                            {
+                                continue;
+                            }
+                            self.remove_candidate_seen_by(&[cells[0], cells[3]], num, &mut result);
+                            if result.will_remove_candidates() {
+                                result
+                                    .candidates_affected
+                                    .extend([end1, connect1, connect2, end2]);
+                                return result;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Check if a row and a column strong link are connected within a box,
+    /// which is the shape of a 2-String Kite.
+    fn is_two_string_kite_shape(
+        unit1: &Unit,
+        unit2: &Unit,
+        connect1: &Candidate,
+        connect2: &Candidate,
+    ) -> bool {
+        matches!(
+            (unit1, unit2),
+            (Unit::Row, Unit::Column) | (Unit::Column, Unit::Row)
+        ) && Self::get_box_index(connect1.row, connect1.col)
+            == Self::get_box_index(connect2.row, connect2.col)
+    }
+
+    /// Find and resolve Turbot Fish.
+    /// Two strong links for a digit connected by a weak link: the digit is true in one of
+    /// the two outer ends and can be removed from all cells that see both ends.
+    /// Skyscrapers and 2-String Kites are special Turbot Fish and have their own strategies.
+    pub fn find_turbot_fish(&self) -> StrategyResult {
+        log::info!("Finding Turbot Fish");
+        let result = self.find_two_linked_strong_links(|unit1, unit2, connect1, connect2| {
+            !Self::is_skyscraper_shape(unit1, unit2, connect1, connect2)
+                && !Self::is_two_string_kite_shape(unit1, unit2, connect1, connect2)
+        });
+        StrategyResult {
+            strategy: Strategy::TurbotFish,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve 2-String Kites.
+    /// A strong link in a row and one in a column for the same digit, with one end of each
+    /// in the same box. The digit can be removed from the cell that sees both other ends.
+    pub fn find_two_string_kite(&self) -> StrategyResult {
+        log::info!("Finding 2-String Kite");
+        let result = self.find_two_linked_strong_links(Self::is_two_string_kite_shape);
+        StrategyResult {
+            strategy: Strategy::TwoStringKite,
+            removals: result,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use rate_my_sudoku::{EMPTY, Resolution, Strategy, StrategyResult, Sudoku, SudokuError, Unit};
+use rate_my_sudoku::{
+    Candidate, EMPTY, Resolution, Strategy, StrategyResult, Sudoku, SudokuError, Unit,
+};
 
 use eframe::Storage;
 use eframe::egui;
@@ -83,6 +85,13 @@ impl SudokuApp {
         let text_color = Color32::BLACK;
         let notes_color = Color32::from_gray(100);
         let shade_color = Color32::from_gray(170);
+        // Color classes of coloring strategies (two per cluster) and almost locked sets
+        let color_classes = [
+            Color32::from_rgb(150, 200, 255), // Blue
+            Color32::from_rgb(255, 220, 120), // Yellow
+            Color32::from_rgb(210, 170, 255), // Purple
+            Color32::from_rgb(150, 235, 220), // Teal
+        ];
 
         // Define stroke widths
         let thin_stroke = Stroke::new(1.0, grid_color);
@@ -138,36 +147,34 @@ impl SudokuApp {
                     }
                     Some(unit) => match self.strategy_result.removals.unit {
                         None => {}
-                        Some(Unit::Row) => {
-                            if !unit.contains(&row) {
-                                painter.rect_filled(
-                                    Rect::from_min_size(
-                                        Pos2::new(
-                                            response.rect.min.x,
-                                            response.rect.min.y + row as f32 * cell_size,
-                                        ),
-                                        Vec2::new(board_size, cell_size),
+                        Some(Unit::Row) if !unit.contains(&row) => {
+                            painter.rect_filled(
+                                Rect::from_min_size(
+                                    Pos2::new(
+                                        response.rect.min.x,
+                                        response.rect.min.y + row as f32 * cell_size,
                                     ),
-                                    0.0,
-                                    shade_color,
-                                );
-                            }
+                                    Vec2::new(board_size, cell_size),
+                                ),
+                                0.0,
+                                shade_color,
+                            );
                         }
-                        Some(Unit::Column) => {
-                            if !unit.contains(&col) {
-                                painter.rect_filled(
-                                    Rect::from_min_size(
-                                        Pos2::new(
-                                            response.rect.min.x + col as f32 * cell_size,
-                                            response.rect.min.y,
-                                        ),
-                                        Vec2::new(cell_size, board_size),
+                        Some(Unit::Row) => {}
+                        Some(Unit::Column) if !unit.contains(&col) => {
+                            painter.rect_filled(
+                                Rect::from_min_size(
+                                    Pos2::new(
+                                        response.rect.min.x + col as f32 * cell_size,
+                                        response.rect.min.y,
                                     ),
-                                    0.0,
-                                    shade_color,
-                                );
-                            }
+                                    Vec2::new(cell_size, board_size),
+                                ),
+                                0.0,
+                                shade_color,
+                            );
                         }
+                        Some(Unit::Column) => {}
                         Some(Unit::Box) => {
                             let box_row = row / 3;
                             let box_col = col / 3;
@@ -256,7 +263,7 @@ impl SudokuApp {
                     let note_size = cell_size / 3.0;
 
                     for n in 1..=9 {
-                        if sudoku.get_candidates(row, col).contains(&n) {
+                        if sudoku.get_candidates(row, col).contains(n) {
                             let note_row = (n - 1) / 3;
                             let note_col = (n - 1) % 3;
 
@@ -280,7 +287,44 @@ impl SudokuApp {
                                 .iter()
                                 .any(|cell| cell.row == row && cell.col == col && cell.num == n);
 
-                            if highlight_affected && !highlight_about_to_be_removed {
+                            let highlight_fin =
+                                self.strategy_result.removals.fins.iter().any(|cell| {
+                                    cell.row == row && cell.col == col && cell.num == n
+                                });
+
+                            let color_class = self
+                                .strategy_result
+                                .removals
+                                .colored_candidates
+                                .iter()
+                                .find(|(cell, _)| {
+                                    cell.row == row && cell.col == col && cell.num == n
+                                })
+                                .map(|&(_, color)| color);
+
+                            if let (Some(color), false) =
+                                (color_class, highlight_about_to_be_removed)
+                            {
+                                let highlight_rect = Rect::from_center_size(
+                                    note_pos,
+                                    Vec2::new(note_size * 0.8, note_size * 0.8),
+                                );
+                                painter.rect_filled(
+                                    highlight_rect,
+                                    2.0,
+                                    color_classes[color % color_classes.len()],
+                                );
+                            } else if highlight_fin {
+                                let highlight_rect = Rect::from_center_size(
+                                    note_pos,
+                                    Vec2::new(note_size * 0.8, note_size * 0.8),
+                                );
+                                painter.rect_filled(
+                                    highlight_rect,
+                                    2.0,
+                                    Color32::from_rgb(200, 220, 255), // Light blue
+                                );
+                            } else if highlight_affected && !highlight_about_to_be_removed {
                                 let highlight_rect = Rect::from_center_size(
                                     note_pos,
                                     Vec2::new(note_size * 0.8, note_size * 0.8),
@@ -324,6 +368,40 @@ impl SudokuApp {
                 }
             }
         }
+
+        // Draw the chain of chaining strategies: strong links solid, weak links dashed
+        let note_center = |candidate: &Candidate| {
+            let note_size = cell_size / 3.0;
+            let note_row = (candidate.num - 1) / 3;
+            let note_col = (candidate.num - 1) % 3;
+            Pos2::new(
+                response.rect.min.x
+                    + candidate.col as f32 * cell_size
+                    + note_col as f32 * note_size
+                    + note_size / 2.0,
+                response.rect.min.y
+                    + candidate.row as f32 * cell_size
+                    + note_row as f32 * note_size
+                    + note_size / 2.0,
+            )
+        };
+        // Grouped nodes are connected at the center of their candidates
+        let node_center = |node: &Vec<Candidate>| {
+            let sum = node
+                .iter()
+                .map(note_center)
+                .fold(Vec2::ZERO, |sum, pos| sum + pos.to_vec2());
+            (sum / node.len() as f32).to_pos2()
+        };
+        let chain_stroke = Stroke::new(2.0, Color32::from_rgb(220, 80, 80));
+        for (i, link) in self.strategy_result.removals.chain.windows(2).enumerate() {
+            let points = [node_center(&link[0]), node_center(&link[1])];
+            if i % 2 == 0 {
+                painter.line_segment(points, chain_stroke);
+            } else {
+                painter.extend(egui::Shape::dashed_line(&points, chain_stroke, 4.0, 4.0));
+            }
+        }
     }
 }
 
@@ -437,6 +515,22 @@ impl eframe::App for SudokuApp {
                         }
                         ui.close_menu();
                     }
+                    if ui.button("Save solve path ...").clicked() {
+                        if let Some(path) = rfd::FileDialog::new()
+                            .add_filter("JSON", &["json"])
+                            .save_file()
+                        {
+                            let mut sudoku = self.sudoku.clone();
+                            sudoku.restore();
+                            let solve_path = sudoku.solve_human_like();
+                            if let Err(err) = std::fs::write(&path, solve_path.to_json()) {
+                                log::error!("Failed to save solve path: {}", err);
+                            } else {
+                                log::info!("Saved solve path to {}", path.display());
+                            }
+                        }
+                        ui.close_menu();
+                    }
                 });
             });
         });
@@ -512,11 +606,27 @@ impl eframe::App for SudokuApp {
                     }
                     // Status information display
                     let status_text = if self.strategy_result.strategy != Strategy::None {
-                        if self.strategy_result.removals.unit.is_some() {
+                        if let (Some(unit), Some(cover_unit)) = (
+                            &self.strategy_result.removals.unit,
+                            &self.strategy_result.removals.cover_unit,
+                        ) {
+                            format!(
+                                "Strategy: {} in {} {:?}, cover {} {:?}",
+                                self.strategy_result.strategy,
+                                unit,
+                                self.strategy_result.removals.unit_index.as_ref().unwrap(),
+                                cover_unit,
+                                self.strategy_result
+                                    .removals
+                                    .cover_unit_index
+                                    .as_ref()
+                                    .unwrap()
+                            )
+                        } else if let Some(unit) = &self.strategy_result.removals.unit {
                             format!(
                                 "Strategy: {} in {} {:?}",
                                 self.strategy_result.strategy,
-                                self.strategy_result.removals.unit.as_ref().unwrap(),
+                                unit,
                                 self.strategy_result.removals.unit_index.as_ref().unwrap()
                             )
                         } else {
@@ -525,7 +635,7 @@ impl eframe::App for SudokuApp {
                     } else if self.sudoku.is_solved() {
                         std::fmt::format(format_args!(
                             "Solved! Effort: {:.1}",
-                            self.sudoku.effort()
+                            self.sudoku.rating_report().effort()
                         ))
                     } else {
                         "Ready".to_string()
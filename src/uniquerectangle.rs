@@ -0,0 +1,437 @@
+use crate::{
+    ALL_DIGITS, Candidate, CandidateSet, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku,
+    Unit,
+};
+
+/// Four cells forming a rectangle in two rows, two columns and two boxes,
+/// all holding the candidates `a` and `b`.
+/// The cells are ordered (row1, col1), (row1, col2), (row2, col1), (row2, col2),
+/// so cells 0 and 3 as well as cells 1 and 2 are diagonally opposite.
+struct UniqueRectangle {
+    cells: [(usize, usize); 4],
+    a: u8,
+    b: u8,
+}
+
+impl UniqueRectangle {
+    /// Diagonally opposite corner of the cell at `index`
+    fn opposite(index: usize) -> usize {
+        3 - index
+    }
+
+    /// Check if the cells at the two indices share a row or column
+    fn adjacent(index1: usize, index2: usize) -> bool {
+        index1 != index2 && Self::opposite(index1) != index2
+    }
+}
+
+impl Sudoku {
+    /// Collect all rectangles that could become a deadly pattern: if the cells held
+    /// only `a` and `b`, the two digits could be swapped and the puzzle had two solutions.
+    fn find_unique_rectangles(&self) -> Vec<UniqueRectangle> {
+        let mut rectangles = Vec::new();
+        for row1 in 0..9 {
+            for row2 in (row1 + 1)..9 {
+                for col1 in 0..9 {
+                    for col2 in (col1 + 1)..9 {
+                        let cells = [(row1, col1), (row1, col2), (row2, col1), (row2, col2)];
+                        // The rectangle has to span exactly two boxes
+                        if (row1 / 3 == row2 / 3) == (col1 / 3 == col2 / 3) {
+                            continue;
+                        }
+                        if cells
+                            .iter()
+                            .any(|&(row, col)| self.board[row][col] != EMPTY)
+                        {
+                            continue;
+                        }
+                        let common: Vec<u8> = cells
+                            .iter()
+                            .fold(ALL_DIGITS, |common, &(row, col)| {
+                                common.intersection(&self.candidates[row][col])
+                            })
+                            .iter()
+                            .collect();
+                        for i in 0..common.len() {
+                            for j in (i + 1)..common.len() {
+                                rectangles.push(UniqueRectangle {
+                                    cells,
+                                    a: common[i],
+                                    b: common[j],
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        rectangles
+    }
+
+    /// Candidates of a rectangle cell other than `a` and `b`
+    fn unique_rectangle_extras(&self, ur: &UniqueRectangle, index: usize) -> CandidateSet {
+        let (row, col) = ur.cells[index];
+        self.candidates[row][col].difference(&CandidateSet::from([ur.a, ur.b]))
+    }
+
+    /// Indices of the rectangle cells holding only `a` and `b`
+    fn unique_rectangle_floor(&self, ur: &UniqueRectangle) -> Vec<usize> {
+        (0..4)
+            .filter(|&index| self.unique_rectangle_extras(ur, index).is_empty())
+            .collect()
+    }
+
+    /// Units shared by the two rectangle cells at the given indices
+    fn unique_rectangle_shared_units(
+        ur: &UniqueRectangle,
+        index1: usize,
+        index2: usize,
+    ) -> Vec<(Unit, usize)> {
+        let (row1, col1) = ur.cells[index1];
+        let (row2, col2) = ur.cells[index2];
+        let mut units = Vec::new();
+        if row1 == row2 {
+            units.push((Unit::Row, row1));
+        }
+        if col1 == col2 {
+            units.push((Unit::Column, col1));
+        }
+        if Self::get_box_index(row1, col1) == Self::get_box_index(row2, col2) {
+            units.push((Unit::Box, Self::get_box_index(row1, col1)));
+        }
+        units
+    }
+
+    /// Check if `num` is a candidate in the unit only within the given cells
+    fn candidate_only_in_cells(
+        &self,
+        unit: &Unit,
+        index: usize,
+        num: u8,
+        cells: &[(usize, usize)],
+    ) -> bool {
+        Self::get_unit_cells(unit, index)
+            .into_iter()
+            .filter(|&(row, col)| self.candidates[row][col].contains(num))
+            .all(|cell| cells.contains(&cell))
+    }
+
+    /// Report the digits `a` and `b` of all four rectangle cells as affected
+    fn push_unique_rectangle(ur: &UniqueRectangle, result: &mut RemovalResult) {
+        for &(row, col) in &ur.cells {
+            for num in [ur.a, ur.b] {
+                result.candidates_affected.push(Candidate { row, col, num });
+            }
+        }
+    }
+
+    /// Find and resolve Unique Rectangles of type 1.
+    /// Three corners hold only `a` and `b`. The fourth corner can't be `a` or `b`,
+    /// otherwise the puzzle would have two solutions, so both are removed there.
+    pub fn find_unique_rectangle_type_1(&self) -> StrategyResult {
+        log::info!("Finding Unique Rectangle type 1");
+        let mut result = RemovalResult::empty();
+        for ur in self.find_unique_rectangles() {
+            let floor = self.unique_rectangle_floor(&ur);
+            if floor.len() != 3 {
+                continue;
+            }
+            let index = (0..4).find(|index| !floor.contains(index)).unwrap();
+            let (row, col) = ur.cells[index];
+            for num in [ur.a, ur.b] {
+                result
+                    .candidates_about_to_be_removed
+                    .insert(Candidate { row, col, num });
+            }
+            Self::push_unique_rectangle(&ur, &mut result);
+            return StrategyResult {
+                strategy: Strategy::UniqueRectangleType1,
+                removals: result,
+            };
+        }
+        StrategyResult {
+            strategy: Strategy::UniqueRectangleType1,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve Unique Rectangles of type 2.
+    /// Two corners in the same row or column hold only `a` and `b`, the other two
+    /// hold the same single extra candidate `x`. One of them has to be `x`, so `x`
+    /// can be removed from all cells that see both of them.
+    pub fn find_unique_rectangle_type_2(&self) -> StrategyResult {
+        log::info!("Finding Unique Rectangle type 2");
+        let mut result = RemovalResult::empty();
+        for ur in self.find_unique_rectangles() {
+            let floor = self.unique_rectangle_floor(&ur);
+            if floor.len() != 2 || !UniqueRectangle::adjacent(floor[0], floor[1]) {
+                continue;
+            }
+            let roof: Vec<usize> = (0..4).filter(|index| !floor.contains(index)).collect();
+            let extras1 = self.unique_rectangle_extras(&ur, roof[0]);
+            let extras2 = self.unique_rectangle_extras(&ur, roof[1]);
+            if extras1.len() != 1 || extras1 != extras2 {
+                continue;
+            }
+            let x = extras1.first().unwrap();
+            self.remove_candidate_seen_by(&[ur.cells[roof[0]], ur.cells[roof[1]]], x, &mut result);
+            if result.will_remove_candidates() {
+                Self::push_unique_rectangle(&ur, &mut result);
+                return StrategyResult {
+                    strategy: Strategy::UniqueRectangleType2,
+                    removals: result,
+                };
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::UniqueRectangleType2,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve Unique Rectangles of type 3.
+    /// Two corners in the same row or column hold only `a` and `b`. One of the other two
+    /// corners has to take one of their extra candidates, so together they act like a single
+    /// cell. If it forms a naked subset with other cells of a shared unit, the subset's digits
+    /// can be removed from the rest of that unit.
+    pub fn find_unique_rectangle_type_3(&self) -> StrategyResult {
+        log::info!("Finding Unique Rectangle type 3");
+        let mut result = RemovalResult::empty();
+        for ur in self.find_unique_rectangles() {
+            let floor = self.unique_rectangle_floor(&ur);
+            if floor.len() != 2 || !UniqueRectangle::adjacent(floor[0], floor[1]) {
+                continue;
+            }
+            let roof: Vec<usize> = (0..4).filter(|index| !floor.contains(index)).collect();
+            let roof_cells = [ur.cells[roof[0]], ur.cells[roof[1]]];
+            let extras = self
+                .unique_rectangle_extras(&ur, roof[0])
+                .union(&self.unique_rectangle_extras(&ur, roof[1]));
+            if extras.len() < 2 {
+                continue;
+            }
+            for (unit, index) in Self::unique_rectangle_shared_units(&ur, roof[0], roof[1]) {
+                let unit_cells = Self::get_unit_cells(&unit, index);
+                // Cells that can form a naked subset with the roof's extra candidates
+                let others: Vec<(usize, usize)> = unit_cells
+                    .iter()
+                    .filter(|&&(row, col)| {
+                        self.board[row][col] == EMPTY
+                            && !roof_cells.contains(&(row, col))
+                            && !self.candidates[row][col].contains(ur.a)
+                            && !self.candidates[row][col].contains(ur.b)
+                    })
+                    .cloned()
+                    .collect();
+                for size in 1..=3 {
+                    for subset in Self::combinations(&others, size) {
+                        let mut nums = extras;
+                        for &(row, col) in &subset {
+                            nums.extend(self.candidates[row][col].iter());
+                        }
+                        if nums.len() != size + 1 {
+                            continue;
+                        }
+                        for &(row, col) in &unit_cells {
+                            if roof_cells.contains(&(row, col)) || subset.contains(&(row, col)) {
+                                continue;
+                            }
+                            for num in nums {
+                                if self.candidates[row][col].contains(num) {
+                                    result.candidates_about_to_be_removed.insert(Candidate {
+                                        row,
+                                        col,
+                                        num,
+                                    });
+                                }
+                            }
+                        }
+                        if result.will_remove_candidates() {
+                            Self::push_unique_rectangle(&ur, &mut result);
+                            for &(row, col) in &subset {
+                                for num in self.candidates[row][col] {
+                                    result.candidates_affected.push(Candidate { row, col, num });
+                                }
+                            }
+                            result.unit = Some(unit);
+                            result.unit_index = Some(vec![index]);
+                            return StrategyResult {
+                                strategy: Strategy::UniqueRectangleType3,
+                                removals: result,
+                            };
+                        }
+                    }
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::UniqueRectangleType3,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve Unique Rectangles of type 4.
+    /// Two corners in the same row or column hold only `a` and `b`. If `a` is a candidate
+    /// only in the other two corners within a unit they share, one of them is `a`. The
+    /// other one then can't be `b`, so `b` is removed from both.
+    pub fn find_unique_rectangle_type_4(&self) -> StrategyResult {
+        log::info!("Finding Unique Rectangle type 4");
+        let mut result = RemovalResult::empty();
+        for ur in self.find_unique_rectangles() {
+            let floor = self.unique_rectangle_floor(&ur);
+            if floor.len() != 2 || !UniqueRectangle::adjacent(floor[0], floor[1]) {
+                continue;
+            }
+            let roof: Vec<usize> = (0..4).filter(|index| !floor.contains(index)).collect();
+            let roof_cells = [ur.cells[roof[0]], ur.cells[roof[1]]];
+            for (unit, index) in Self::unique_rectangle_shared_units(&ur, roof[0], roof[1]) {
+                for (locked, removed) in [(ur.a, ur.b), (ur.b, ur.a)] {
+                    if !self.candidate_only_in_cells(&unit, index, locked, &roof_cells) {
+                        continue;
+                    }
+                    for (row, col) in roof_cells {
+                        result.candidates_about_to_be_removed.insert(Candidate {
+                            row,
+                            col,
+                            num: removed,
+                        });
+                    }
+                    Self::push_unique_rectangle(&ur, &mut result);
+                    result.unit = Some(unit);
+                    result.unit_index = Some(vec![index]);
+                    return StrategyResult {
+                        strategy: Strategy::UniqueRectangleType4,
+                        removals: result,
+                    };
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::UniqueRectangleType4,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve Unique Rectangles of type 5.
+    /// Two diagonal corners, or one corner, hold only `a` and `b`. The other corners hold
+    /// the same single extra candidate `x`, one of which has to be true. So `x` can be
+    /// removed from all cells that see every corner with the extra candidate.
+    pub fn find_unique_rectangle_type_5(&self) -> StrategyResult {
+        log::info!("Finding Unique Rectangle type 5");
+        let mut result = RemovalResult::empty();
+        for ur in self.find_unique_rectangles() {
+            let floor = self.unique_rectangle_floor(&ur);
+            let diagonal_floor =
+                floor.len() == 2 && UniqueRectangle::opposite(floor[0]) == floor[1];
+            if floor.len() != 1 && !diagonal_floor {
+                continue;
+            }
+            let roof: Vec<usize> = (0..4).filter(|index| !floor.contains(index)).collect();
+            let extras = self.unique_rectangle_extras(&ur, roof[0]);
+            if extras.len() != 1
+                || roof
+                    .iter()
+                    .any(|&index| self.unique_rectangle_extras(&ur, index) != extras)
+            {
+                continue;
+            }
+            let x = extras.first().unwrap();
+            let roof_cells: Vec<(usize, usize)> =
+                roof.iter().map(|&index| ur.cells[index]).collect();
+            self.remove_candidate_seen_by(&roof_cells, x, &mut result);
+            if result.will_remove_candidates() {
+                Self::push_unique_rectangle(&ur, &mut result);
+                return StrategyResult {
+                    strategy: Strategy::UniqueRectangleType5,
+                    removals: result,
+                };
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::UniqueRectangleType5,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve Unique Rectangles of type 6.
+    /// Two diagonal corners hold only `a` and `b`. If `a` is a candidate in both rows and
+    /// both columns of the rectangle only within the rectangle, placing `a` in one of the
+    /// other two corners would force the deadly pattern. So `a` is removed from them.
+    pub fn find_unique_rectangle_type_6(&self) -> StrategyResult {
+        log::info!("Finding Unique Rectangle type 6");
+        let mut result = RemovalResult::empty();
+        for ur in self.find_unique_rectangles() {
+            let floor = self.unique_rectangle_floor(&ur);
+            if floor.len() != 2 || UniqueRectangle::opposite(floor[0]) != floor[1] {
+                continue;
+            }
+            let (row1, col1) = ur.cells[0];
+            let (row2, col2) = ur.cells[3];
+            for num in [ur.a, ur.b] {
+                let locked = [
+                    (Unit::Row, row1),
+                    (Unit::Row, row2),
+                    (Unit::Column, col1),
+                    (Unit::Column, col2),
+                ]
+                .iter()
+                .all(|(unit, index)| self.candidate_only_in_cells(unit, *index, num, &ur.cells));
+                if !locked {
+                    continue;
+                }
+                for index in (0..4).filter(|index| !floor.contains(index)) {
+                    let (row, col) = ur.cells[index];
+                    result
+                        .candidates_about_to_be_removed
+                        .insert(Candidate { row, col, num });
+                }
+                Self::push_unique_rectangle(&ur, &mut result);
+                return StrategyResult {
+                    strategy: Strategy::UniqueRectangleType6,
+                    removals: result,
+                };
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::UniqueRectangleType6,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve Hidden Unique Rectangles.
+    /// One corner holds only `a` and `b`. If `a` is a candidate in the row and the column
+    /// of the opposite corner only within the rectangle, that corner can't be `b`: the
+    /// other two corners would both be `a`, forcing the first corner to `b` as well,
+    /// which is the deadly pattern. So `b` is removed from the opposite corner.
+    pub fn find_hidden_unique_rectangle(&self) -> StrategyResult {
+        log::info!("Finding Hidden Unique Rectangle");
+        let mut result = RemovalResult::empty();
+        for ur in self.find_unique_rectangles() {
+            for floor in self.unique_rectangle_floor(&ur) {
+                let opposite = UniqueRectangle::opposite(floor);
+                let (row, col) = ur.cells[opposite];
+                for (locked, removed) in [(ur.a, ur.b), (ur.b, ur.a)] {
+                    if !self.candidate_only_in_cells(&Unit::Row, row, locked, &ur.cells)
+                        || !self.candidate_only_in_cells(&Unit::Column, col, locked, &ur.cells)
+                    {
+                        continue;
+                    }
+                    result.candidates_about_to_be_removed.insert(Candidate {
+                        row,
+                        col,
+                        num: removed,
+                    });
+                    Self::push_unique_rectangle(&ur, &mut result);
+                    return StrategyResult {
+                        strategy: Strategy::HiddenUniqueRectangle,
+                        removals: result,
+                    };
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::HiddenUniqueRectangle,
+            removals: result,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{Candidate, RemovalResult, Strategy, StrategyResult, Sudoku};
+
+impl Sudoku {
+    /// Find and resolve W-Wings.
+    /// Two bivalue cells with the same candidates {x, y} that don't see each other are
+    /// connected by a strong link on `x`: one end of the link sees the first cell, the
+    /// other end sees the second. If neither cell were `y`, both would be `x` and the
+    /// strong link would lose its digit. So one of the cells is `y` and `y` can be
+    /// removed from all cells that see both of them.
+    pub fn find_wwing(&self) -> StrategyResult {
+        log::info!("Finding W-Wing");
+        let mut result = RemovalResult::empty();
+        let bivalue_cells = self.cells_with_candidate_count(2);
+        for i in 0..bivalue_cells.len() {
+            for j in (i + 1)..bivalue_cells.len() {
+                let cell1 = bivalue_cells[i];
+                let cell2 = bivalue_cells[j];
+                let nums = self.candidates[cell1.0][cell1.1];
+                if nums != self.candidates[cell2.0][cell2.1]
+                    || Self::cells_see_each_other(cell1, cell2)
+                {
+                    continue;
+                }
+                let nums: Vec<u8> = nums.iter().collect();
+                for (x, y) in [(nums[0], nums[1]), (nums[1], nums[0])] {
+                    for (link, _) in self.find_strong_links(x) {
+                        let (start, end) = (link.base, link.top);
+                        let start_cell = (start.row, start.col);
+                        let end_cell = (end.row, end.col);
+                        // The link must not touch the bivalue cells themselves
+                        if [start_cell, end_cell].contains(&cell1)
+                            || [start_cell, end_cell].contains(&cell2)
+                        {
+                            continue;
+                        }
+                        let (start, end) = if Self::cells_see_each_other(start_cell, cell1)
+                            && Self::cells_see_each_other(end_cell, cell2)
+                        {
+                            (start, end)
+                        } else if Self::cells_see_each_other(end_cell, cell1)
+                            && Self::cells_see_each_other(start_cell, cell2)
+                        {
+                            (end, start)
+                        } else {
+                            continue;
+                        };
+                        self.remove_candidate_seen_by(&[cell1, cell2], y, &mut result);
+                        if result.will_remove_candidates() {
+                            // The bivalue cells followed by the strong link
+                            for (row, col) in [cell1, cell2] {
+                                for num in [x, y] {
+                                    result.candidates_affected.push(Candidate { row, col, num });
+                                }
+                            }
+                            result.candidates_affected.push(start);
+                            result.candidates_affected.push(end);
+                            return StrategyResult {
+                                strategy: Strategy::WWing,
+                                removals: result,
+                            };
+                        }
+                    }
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::WWing,
+            removals: result,
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{Candidate, CandidateSet, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku};
+
+/// Two pincer cells and the candidate they share
+type Pincers = ((usize, usize), (usize, usize), u8);
+
+impl Sudoku {
+    /// Collect all unsolved cells with exactly `count` candidates
+    pub(crate) fn cells_with_candidate_count(&self, count: usize) -> Vec<(usize, usize)> {
+        let mut cells = Vec::new();
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.board[row][col] == EMPTY && self.candidates[row][col].len() == count {
+                    cells.push((row, col));
+                }
+            }
+        }
+        cells
+    }
+
+    /// Remove `num` from all cells that see every one of the given cells.
+    pub(crate) fn remove_candidate_seen_by(
+        &self,
+        cells: &[(usize, usize)],
+        num: u8,
+        result: &mut RemovalResult,
+    ) {
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.candidates[row][col].contains(num)
+                    && cells
+                        .iter()
+                        .all(|&cell| Self::cells_see_each_other(cell, (row, col)))
+                {
+                    result
+                        .candidates_about_to_be_removed
+                        .insert(Candidate { row, col, num });
+                }
+            }
+        }
+    }
+
+    /// Report the pivot followed by the two pincers as affected candidates.
+    fn push_wing_cells(&self, cells: &[(usize, usize)], result: &mut RemovalResult) {
+        for &(row, col) in cells {
+            let nums: Vec<u8> = self.candidates[row][col].iter().collect();
+            result
+                .candidates_affected
+                .extend(nums.into_iter().map(|num| Candidate { row, col, num }));
+        }
+    }
+
+    /// Find the pincers for a pivot: pairs of bivalue cells that see the pivot, each sharing
+    /// exactly one candidate with `pivot_nums` and with the same remaining candidate `z`.
+    /// Yields the two pincers and `z`.
+    fn find_wing_pincers(
+        &self,
+        pivot: (usize, usize),
+        pivot_nums: &CandidateSet,
+        bivalue_cells: &[(usize, usize)],
+    ) -> Vec<Pincers> {
+        let pincers: Vec<(usize, usize)> = bivalue_cells
+            .iter()
+            .filter(|&&cell| Self::cells_see_each_other(pivot, cell))
+            .cloned()
+            .collect();
+        let mut wings = Vec::new();
+        for i in 0..pincers.len() {
+            for j in (i + 1)..pincers.len() {
+                let (r1, c1) = pincers[i];
+                let (r2, c2) = pincers[j];
+                let nums1 = &self.candidates[r1][c1];
+                let nums2 = &self.candidates[r2][c2];
+                if nums1 == nums2 {
+                    continue;
+                }
+                // The pincers share exactly one candidate `z`, the others must match the pivot
+                let common = nums1.intersection(nums2);
+                if common.len() != 1 {
+                    continue;
+                }
+                let z = common.first().unwrap();
+                let mut wing_nums = nums1.union(nums2).difference(&common);
+                if wing_nums.len() != 2 {
+                    continue;
+                }
+                if pivot_nums.len() == 3 {
+                    wing_nums.insert(z);
+                }
+                if &wing_nums == pivot_nums {
+                    wings.push((pincers[i], pincers[j], z));
+                }
+            }
+        }
+        wings
+    }
+
+    /// Find and resolve XY-Wings.
+    /// A bivalue pivot cell {x, y} sees two bivalue pincers {x, z} and {y, z}. Whichever
+    /// value the pivot takes, one of the pincers will be `z`, so `z` can be removed from
+    /// all cells that see both pincers. The pivot and pincers are reported in this order
+    /// as affected candidates.
+    pub fn find_xywing(&self) -> StrategyResult {
+        log::info!("Finding XY-Wing");
+        let mut result = RemovalResult::empty();
+        let bivalue_cells = self.cells_with_candidate_count(2);
+        for &pivot in &bivalue_cells {
+            let pivot_nums = &self.candidates[pivot.0][pivot.1];
+            for (pincer1, pincer2, z) in self.find_wing_pincers(pivot, pivot_nums, &bivalue_cells) {
+                self.remove_candidate_seen_by(&[pincer1, pincer2], z, &mut result);
+                if result.will_remove_candidates() {
+                    self.push_wing_cells(&[pivot, pincer1, pincer2], &mut result);
+                    return StrategyResult {
+                        strategy: Strategy::XYWing,
+                        removals: result,
+                    };
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::XYWing,
+            removals: result,
+        }
+    }
+
+    /// Find and resolve XYZ-Wings.
+    /// Like an XY-Wing, but the pivot holds all three candidates {x, y, z}. Since `z` is
+    /// true in the pivot or one of the pincers, it can only be removed from cells that
+    /// see the pivot and both pincers.
+    pub fn find_xyzwing(&self) -> StrategyResult {
+        log::info!("Finding XYZ-Wing");
+        let mut result = RemovalResult::empty();
+        let bivalue_cells = self.cells_with_candidate_count(2);
+        for pivot in self.cells_with_candidate_count(3) {
+            let pivot_nums = &self.candidates[pivot.0][pivot.1];
+            for (pincer1, pincer2, z) in self.find_wing_pincers(pivot, pivot_nums, &bivalue_cells) {
+                self.remove_candidate_seen_by(&[pivot, pincer1, pincer2], z, &mut result);
+                if result.will_remove_candidates() {
+                    self.push_wing_cells(&[pivot, pincer1, pincer2], &mut result);
+                    return StrategyResult {
+                        strategy: Strategy::XYZWing,
+                        removals: result,
+                    };
+                }
+            }
+        }
+        StrategyResult {
+            strategy: Strategy::XYZWing,
+            removals: result,
+        }
+    }
+}
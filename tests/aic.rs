@@ -0,0 +1,146 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    fn nodes(nodes: &[&[(usize, usize, u8)]]) -> Vec<Vec<Candidate>> {
+        nodes
+            .iter()
+            .map(|node| {
+                node.iter()
+                    .map(|&(row, col, num)| Candidate { row, col, num })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_continuous_nice_loop() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000134800001890745984507310807643051400218007610975408008009104149080500006451000",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_continuous_nice_loop();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::ContinuousNiceLoop);
+        let removals = result.removals;
+        assert_eq!(
+            removals.chain,
+            nodes(&[
+                &[(3, 6, 9)],
+                &[(3, 6, 2)],
+                &[(8, 6, 2)],
+                &[(8, 6, 9)],
+                &[(3, 6, 9)]
+            ])
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 4,
+            col: 6,
+            num: 9
+        }));
+    }
+
+    #[test]
+    fn test_discontinuous_nice_loop() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000034800001000705904500300807043051400218007610970408008009104109000500006450000",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_discontinuous_nice_loop();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::DiscontinuousNiceLoop);
+        let removals = result.removals;
+        assert_eq!(
+            removals.chain,
+            nodes(&[&[(4, 6, 6)], &[(3, 6, 6)], &[(4, 7, 6)], &[(4, 6, 6)]])
+        );
+        let cell = removals.sets_cell.expect("Expected the loop to set a cell");
+        assert_eq!((cell.row, cell.col, cell.num), (4, 6, 6));
+    }
+
+    #[test]
+    fn test_aic() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000134800001890745984507310807643051400218007610975408008009104149080500006451080",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_aic();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::Aic);
+        let removals = result.removals;
+        assert_eq!(
+            removals.chain,
+            nodes(&[&[(0, 0, 7)], &[(0, 1, 7)], &[(8, 1, 7)], &[(8, 0, 7)]])
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 6,
+            col: 0,
+            num: 7
+        }));
+    }
+
+    #[test]
+    fn test_grouped_continuous_nice_loop() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "390057020627049508015020790009071050100060907756930800073090180500710309961080075",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_grouped_continuous_nice_loop();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::GroupedContinuousNiceLoop);
+        let removals = result.removals;
+        assert_eq!(
+            removals.chain,
+            nodes(&[
+                &[(3, 8, 6)],
+                &[(3, 6, 6)],
+                &[(0, 6, 6)],
+                &[(0, 8, 6), (2, 8, 6)],
+                &[(3, 8, 6)]
+            ])
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 6,
+            col: 8,
+            num: 6
+        }));
+    }
+
+    #[test]
+    fn test_grouped_aic() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "200845900490670580578390000085219000910734058000586190150967820820453009009128005",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_grouped_aic();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::GroupedAic);
+        let removals = result.removals;
+        assert_eq!(
+            removals.chain,
+            nodes(&[
+                &[(0, 1, 3)],
+                &[(0, 2, 3), (1, 2, 3)],
+                &[(6, 2, 3)],
+                &[(6, 8, 3)]
+            ])
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 0,
+            col: 8,
+            num: 3
+        }));
+    }
+}
@@ -0,0 +1,144 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    fn colored_cells(colored: &[(Candidate, usize)], color: usize) -> Vec<(usize, usize)> {
+        let mut cells: Vec<(usize, usize)> = colored
+            .iter()
+            .filter(|(_, index)| *index == color)
+            .map(|(candidate, _)| (candidate.row, candidate.col))
+            .collect();
+        cells.dedup();
+        cells
+    }
+
+    #[test]
+    fn test_als_xz() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "040981070718360094009470008467528900153794862892613457084059700900047080070836040",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_als_xz();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::AlsXz);
+        let removals = result.removals;
+        assert_eq!(
+            colored_cells(&removals.colored_candidates, 0),
+            vec![(2, 1), (2, 5)]
+        );
+        assert_eq!(
+            colored_cells(&removals.colored_candidates, 1),
+            vec![(7, 1), (8, 0)]
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 2,
+            col: 0,
+            num: 5
+        }));
+    }
+
+    #[test]
+    fn test_als_xz_doubly_linked() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "398657421627049538415020796849271653100060907756930802273090184500710369961483275",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_als_xz();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::AlsXz);
+        let removals = result.removals;
+        assert_eq!(
+            colored_cells(&removals.colored_candidates, 0),
+            vec![(4, 3), (4, 7)]
+        );
+        assert_eq!(
+            colored_cells(&removals.colored_candidates, 1),
+            vec![(2, 3), (6, 3)]
+        );
+        // Both sets are locked, so 4 is locked in r5c8
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        assert!(candidates.contains(&Candidate {
+            row: 4,
+            col: 5,
+            num: 4
+        }));
+        assert!(candidates.contains(&Candidate {
+            row: 5,
+            col: 7,
+            num: 4
+        }));
+    }
+
+    #[test]
+    fn test_als_xy_wing() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "261845937493672581578391000085219004912734658000586192150967820820453019009128005",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_als_xy_wing();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::AlsXyWing);
+        let removals = result.removals;
+        assert_eq!(
+            colored_cells(&removals.colored_candidates, 0),
+            vec![(2, 6), (2, 8)]
+        );
+        assert_eq!(
+            colored_cells(&removals.colored_candidates, 1),
+            vec![(7, 6), (8, 6)]
+        );
+        assert_eq!(
+            colored_cells(&removals.colored_candidates, 2),
+            vec![(2, 7), (3, 7)]
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        assert!(candidates.contains(&Candidate {
+            row: 3,
+            col: 6,
+            num: 7
+        }));
+        assert!(candidates.contains(&Candidate {
+            row: 8,
+            col: 7,
+            num: 7
+        }));
+    }
+
+    #[test]
+    fn test_death_blossom() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "140006897790008546865749213216497385937800624458263971529674138684000052371082069",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_death_blossom();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::DeathBlossom);
+        let removals = result.removals;
+        // The stem comes first, followed by one petal per candidate of the stem
+        assert_eq!(colored_cells(&removals.colored_candidates, 0), vec![(0, 3)]);
+        assert_eq!(colored_cells(&removals.colored_candidates, 1), vec![(1, 3)]);
+        assert_eq!(
+            colored_cells(&removals.colored_candidates, 2),
+            vec![(7, 4), (8, 3)]
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        assert!(candidates.contains(&Candidate {
+            row: 1,
+            col: 4,
+            num: 1
+        }));
+        assert!(candidates.contains(&Candidate {
+            row: 7,
+            col: 3,
+            num: 1
+        }));
+    }
+}
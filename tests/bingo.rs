@@ -0,0 +1,40 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    #[test]
+    fn test_bowmans_bingo() {
+        let board =
+            "215040709378002405469700023603004070107000034894007000032400007740980302080273040";
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(board).expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_bowmans_bingo();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::BowmansBingo);
+        let removals = result.removals;
+        assert_eq!(removals.trial_depth, 1);
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 0,
+            col: 5,
+            num: 8
+        }));
+        let mut solution = Sudoku::from_string(board).expect("Failed to create Sudoku from string");
+        solution.solve_by_backtracking();
+        assert_ne!(solution.board[0][5], 8);
+    }
+
+    #[test]
+    fn test_bowmans_bingo_disabled() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "215040709378002405469700023603004070107000034894007000032400007740980302080273040",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        sudoku.options.max_trial_depth = 0;
+        let result = sudoku.find_bowmans_bingo();
+        println!("{:?}", result);
+        assert!(!result.removals.will_remove_candidates());
+    }
+}
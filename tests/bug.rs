@@ -0,0 +1,39 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    #[test]
+    fn test_bug_plus_one() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "897203006512648379643790200258137964469002731371469020786924153130076092920300607",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_bug_plus_one();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::BugPlusOne);
+        let removals = result.removals;
+        let cell = removals.sets_cell.expect("BUG+1 should set a cell");
+        assert_eq!((cell.row, cell.col, cell.num), (8, 4, 5));
+        let candidates = removals.candidates_about_to_be_removed;
+        for num in [1, 8] {
+            assert!(candidates.contains(&Candidate {
+                row: 8,
+                col: 4,
+                num
+            }));
+        }
+    }
+
+    #[test]
+    fn test_bug_plus_one_none() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "006020000000901306730000051903200600060010080008005109640000012305102000000080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_bug_plus_one();
+        println!("{:?}", result);
+        assert!(!result.removals.will_remove_candidates());
+        assert!(result.removals.sets_cell.is_none());
+    }
+}
@@ -0,0 +1,39 @@
+mod tests {
+    use rate_my_sudoku::{ALL_DIGITS, CandidateSet};
+
+    #[test]
+    fn test_candidate_set_insert_remove() {
+        let mut set = CandidateSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(7));
+        assert!(set.insert(2));
+        assert!(!set.insert(7));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(2));
+        assert!(!set.contains(3));
+        assert!(set.remove(7));
+        assert!(!set.remove(7));
+        assert_eq!(set, CandidateSet::from([2]));
+    }
+
+    #[test]
+    fn test_candidate_set_operations() {
+        let a = CandidateSet::from([1, 3, 5, 7]);
+        let b = CandidateSet::from([3, 4, 5]);
+        assert_eq!(a.union(&b), CandidateSet::from([1, 3, 4, 5, 7]));
+        assert_eq!(a.intersection(&b), CandidateSet::from([3, 5]));
+        assert_eq!(a.difference(&b), CandidateSet::from([1, 7]));
+        assert!(!a.is_disjoint(&b));
+        assert!(CandidateSet::from([3, 5]).is_subset(&a));
+        assert_eq!(ALL_DIGITS.len(), 9);
+        assert_eq!(ALL_DIGITS.difference(&a).first(), Some(2));
+    }
+
+    #[test]
+    fn test_candidate_set_iterates_in_order() {
+        let set = CandidateSet::from([9, 4, 1, 6]);
+        assert_eq!(set.iter().collect::<Vec<u8>>(), vec![1, 4, 6, 9]);
+        assert_eq!(set.iter().len(), 4);
+        assert_eq!(format!("{:?}", set), "{1, 4, 6, 9}");
+    }
+}
@@ -0,0 +1,85 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    #[test]
+    fn test_x_chain() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "106020008804901306730800051913208605560019080408005109640000812385102000201080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_x_chain();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::XChain);
+        let removals = result.removals;
+        let expected: Vec<Vec<Candidate>> = [(0, 5), (0, 3), (4, 3), (4, 8), (5, 7), (8, 7)]
+            .into_iter()
+            .map(|(row, col)| vec![Candidate { row, col, num: 3 }])
+            .collect();
+        assert_eq!(removals.chain, expected);
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 8,
+            col: 5,
+            num: 3
+        }));
+    }
+
+    #[test]
+    fn test_xy_chain() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "106020000800901306730000051913200600560010080408005109640000012305102000200080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_xy_chain();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::XYChain);
+        let removals = result.removals;
+        let expected: Vec<Vec<Candidate>> = [
+            (0, 1, 9),
+            (0, 1, 5),
+            (1, 1, 5),
+            (1, 1, 2),
+            (5, 1, 2),
+            (5, 1, 7),
+            (8, 1, 7),
+            (8, 1, 9),
+        ]
+        .into_iter()
+        .map(|(row, col, num)| vec![Candidate { row, col, num }])
+        .collect();
+        assert_eq!(removals.chain, expected);
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 7,
+            col: 1,
+            num: 9
+        }));
+    }
+
+    #[test]
+    fn test_xy_chain_max_length() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "106020000800901306730000051913200600560010080408005109640000012305102000200080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        sudoku.options.max_chain_length = 6;
+        let result = sudoku.find_xy_chain();
+        println!("{:?}", result);
+        assert!(result.removals.chain.len() <= 6);
+        assert!(
+            !result
+                .removals
+                .candidates_about_to_be_removed
+                .contains(&Candidate {
+                    row: 7,
+                    col: 1,
+                    num: 9
+                })
+        );
+    }
+}
@@ -93,4 +93,74 @@ mod tests {
             num: 4
         }));
     }
+
+    #[test]
+    fn test_claiming_triple_row() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000000807790008000065740203216497385937800624008263000029074030604000052301082000",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_claiming_pair_in_rows();
+        println!("{:?}", result);
+        assert_eq!(result.unit, Some(Unit::Row));
+        assert_eq!(result.unit_index, Some(vec![0]));
+        assert!(result.sets_cell.is_none());
+        let candidates_affected = result.candidates_affected;
+        assert_eq!(candidates_affected.len(), 3);
+        for col in 3..6 {
+            assert!(candidates_affected.contains(&Candidate {
+                row: 0,
+                col,
+                num: 5
+            }));
+        }
+        let removals = result.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 2);
+        assert!(removals.contains(&Candidate {
+            row: 1,
+            col: 3,
+            num: 5
+        }));
+        assert!(removals.contains(&Candidate {
+            row: 1,
+            col: 4,
+            num: 5
+        }));
+    }
+
+    #[test]
+    fn test_claiming_triple_col() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "600215937531079000927030001000092070309000012000083049750001090413950700890307100",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_claiming_pair_in_cols();
+        println!("{:?}", result);
+        assert_eq!(result.unit, Some(Unit::Column));
+        assert_eq!(result.unit_index, Some(vec![1]));
+        assert!(result.sets_cell.is_none());
+        let candidates_affected = result.candidates_affected;
+        assert_eq!(candidates_affected.len(), 3);
+        for row in 3..6 {
+            assert!(candidates_affected.contains(&Candidate {
+                row,
+                col: 1,
+                num: 6
+            }));
+        }
+        let removals = result.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 2);
+        assert!(removals.contains(&Candidate {
+            row: 3,
+            col: 2,
+            num: 6
+        }));
+        assert!(removals.contains(&Candidate {
+            row: 5,
+            col: 2,
+            num: 6
+        }));
+    }
 }
@@ -0,0 +1,78 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    #[test]
+    fn test_simple_colors_trap() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "006020000000901306730000051903200600060010080008005109640000012305102000000080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_simple_colors_trap();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::SimpleColorsTrap);
+        let removals = result.removals;
+        let colored = removals.colored_candidates;
+        assert_eq!(colored.len(), 4);
+        for (row, col, color) in [(5, 7, 0), (8, 8, 0), (8, 7, 1), (4, 8, 1)] {
+            assert!(colored.contains(&(Candidate { row, col, num: 3 }, color)));
+        }
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        for (row, col) in [(8, 3), (8, 5)] {
+            assert!(candidates.contains(&Candidate { row, col, num: 3 }));
+        }
+    }
+
+    #[test]
+    fn test_simple_colors_wrap() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "156327498824951376739800251913278645562419783408005109647000812385102900201080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_simple_colors_wrap();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::SimpleColorsWrap);
+        let removals = result.removals;
+        // The removed candidates are exactly the color that sees itself
+        let wrapped: Vec<Candidate> = removals
+            .colored_candidates
+            .iter()
+            .filter(|(_, color)| *color == 0)
+            .map(|(candidate, _)| *candidate)
+            .collect();
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        for (row, col) in [(6, 4), (8, 5)] {
+            assert!(candidates.contains(&Candidate { row, col, num: 3 }));
+            assert!(wrapped.contains(&Candidate { row, col, num: 3 }));
+        }
+    }
+
+    #[test]
+    fn test_multi_colors() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000045096594672138006908405765283914918764352243591687152407869009026500630859000",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_multi_colors();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::MultiColors);
+        let removals = result.removals;
+        // Two clusters with two colors each
+        let colored = removals.colored_candidates;
+        assert_eq!(colored.len(), 8);
+        for color in 0..4 {
+            assert!(colored.iter().any(|(_, c)| *c == color));
+        }
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 8,
+            col: 7,
+            num: 7
+        }));
+    }
+}
@@ -0,0 +1,102 @@
+mod tests {
+    use rate_my_sudoku::{SolverConfig, SolverProfile, Strategy, StrategyRegistry, Sudoku};
+
+    const EASY: &str =
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+    const HARD: &str =
+        "000008009600000324240000005006000001050031000800000002500364000000090060000700090";
+    const LONG_CHAINS: &str =
+        "200070040006300500300009006400001080000050000070900000700600001009002400010040000";
+
+    #[test]
+    fn test_singles_and_pairs() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(EASY).expect("Failed to create Sudoku from string");
+        sudoku.config = SolverConfig::singles_and_pairs();
+        assert!(sudoku.solve_human_like().solved);
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(HARD).expect("Failed to create Sudoku from string");
+        sudoku.config = SolverProfile::SinglesAndPairs.into();
+        assert!(!sudoku.solve_human_like().solved);
+        let config = SolverConfig::singles_and_pairs();
+        assert!(
+            sudoku
+                .rating
+                .keys()
+                .all(|&strategy| config.is_enabled(strategy))
+        );
+    }
+
+    #[test]
+    fn test_disabled_strategy() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(EASY).expect("Failed to create Sudoku from string");
+        sudoku.config.disabled.insert(Strategy::ObviousSingle);
+        assert!(sudoku.solve_human_like().solved);
+        assert!(!sudoku.rating.contains_key(&Strategy::ObviousSingle));
+    }
+
+    #[test]
+    fn test_order() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(EASY).expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        assert_eq!(sudoku.next_step().strategy, Strategy::ObviousSingle);
+        sudoku.config.order = vec![Strategy::HiddenSingle];
+        assert_eq!(sudoku.next_step().strategy, Strategy::HiddenSingle);
+    }
+
+    #[test]
+    fn test_effort_override() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(EASY).expect("Failed to create Sudoku from string");
+        assert!(sudoku.solve_human_like().solved);
+        let effort = sudoku.rating_report().effort();
+        sudoku.config.efforts = StrategyRegistry::builtin()
+            .iter()
+            .map(|strategy| (strategy.strategy(), 2 * strategy.effort()))
+            .collect();
+        sudoku.restore();
+        assert!(sudoku.solve_human_like().solved);
+        assert_eq!(sudoku.rating_report().effort(), 2.0 * effort);
+    }
+
+    #[test]
+    fn test_sudoku_explainer_weights() {
+        let registry = StrategyRegistry::builtin();
+        let config = SolverConfig::sudoku_explainer();
+        assert_eq!(config.effort(Strategy::HiddenSingle, &registry), Some(15));
+        let order: Vec<Strategy> = config
+            .strategies(&registry)
+            .iter()
+            .map(|strategy| strategy.strategy())
+            .collect();
+        let position = |strategy| order.iter().position(|&other| other == strategy);
+        assert_eq!(order.len(), registry.len());
+        assert!(position(Strategy::XWing) < position(Strategy::HiddenPair));
+        assert!(position(Strategy::HiddenSingle) < position(Strategy::ObviousSingle));
+    }
+
+    #[test]
+    fn test_sudoku_explainer_chain_bonus() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(LONG_CHAINS).expect("Failed to create Sudoku from string");
+        sudoku.config = SolverConfig::sudoku_explainer();
+        sudoku.calc_candidates();
+        let mut longest = 0;
+        while !sudoku.is_solved() {
+            let result = sudoku.next_step();
+            assert_ne!(result.strategy, Strategy::None);
+            let count = result.removals.candidates_about_to_be_removed.len() as i32;
+            let effort = sudoku.step_effort(&result).unwrap();
+            if result.removals.trial_depth == 0 {
+                assert!(effort / count - sudoku.strategy_effort(result.strategy).unwrap() < 10);
+            }
+            assert!(effort / count <= 110);
+            longest = longest.max(result.removals.chain.len());
+            sudoku.apply(&result);
+        }
+        assert!(longest > 8);
+        assert!(sudoku.rating_report().effort() <= 110.0);
+    }
+}
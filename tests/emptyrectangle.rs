@@ -0,0 +1,31 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku, Unit};
+
+    #[test]
+    fn test_empty_rectangle() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "106020000000901306730000051913200600060010080008005109640000012305102000200080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_empty_rectangle();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::EmptyRectangle);
+        let removals = result.removals;
+        assert_eq!(removals.unit, Some(Unit::Box));
+        assert_eq!(removals.unit_index, Some(vec![2]));
+        // The box cells followed by the strong link in row 4
+        let candidates_affected = removals.candidates_affected;
+        assert_eq!(candidates_affected.len(), 4);
+        for (row, col) in [(1, 7), (2, 6), (4, 6), (4, 2)] {
+            assert!(candidates_affected.contains(&Candidate { row, col, num: 2 }));
+        }
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 1,
+            col: 2,
+            num: 2
+        }));
+    }
+}
@@ -0,0 +1,98 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku, Unit};
+
+    #[test]
+    fn test_finned_xwing_rows() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "006020000000901306730000051903200600060010080008005109640000012305102000000080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_finned_xwing();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::FinnedXWing);
+        let removals = result.removals;
+        assert_eq!(removals.unit, Some(Unit::Row));
+        assert_eq!(removals.unit_index, Some(vec![0, 6]));
+        assert_eq!(removals.cover_unit, Some(Unit::Column));
+        assert_eq!(removals.cover_unit_index, Some(vec![3, 5]));
+        assert_eq!(
+            removals.fins,
+            vec![Candidate {
+                row: 6,
+                col: 4,
+                num: 3
+            }]
+        );
+        assert_eq!(removals.candidates_affected.len(), 5);
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        // Only cells in the fin's box are eliminated, (2, 3) and (2, 5) are not affected
+        for (row, col) in [(8, 3), (8, 5)] {
+            assert!(candidates.contains(&Candidate { row, col, num: 3 }));
+        }
+    }
+
+    #[test]
+    fn test_sashimi_xwing_cols() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "156327498824951376739800051913278645560419080408005109640000812385102000201080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_sashimi_xwing();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::SashimiXWing);
+        let removals = result.removals;
+        assert_eq!(removals.unit, Some(Unit::Column));
+        assert_eq!(removals.unit_index, Some(vec![4, 7]));
+        assert_eq!(removals.cover_unit, Some(Unit::Row));
+        assert_eq!(removals.cover_unit_index, Some(vec![5, 8]));
+        assert_eq!(
+            removals.fins,
+            vec![Candidate {
+                row: 6,
+                col: 4,
+                num: 3
+            }]
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 8,
+            col: 5,
+            num: 3
+        }));
+    }
+
+    #[test]
+    fn test_finned_swordfish_cols() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "156320098804901306730800051913278645560419080408005109640000812385102000201080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_finned_swordfish();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::FinnedSwordfish);
+        let removals = result.removals;
+        assert_eq!(removals.unit, Some(Unit::Column));
+        assert_eq!(removals.unit_index, Some(vec![2, 3, 8]));
+        assert_eq!(removals.cover_unit_index, Some(vec![4, 6, 8]));
+        assert_eq!(
+            removals.fins,
+            vec![Candidate {
+                row: 7,
+                col: 8,
+                num: 7
+            }]
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 8,
+            col: 7,
+            num: 7
+        }));
+    }
+}
@@ -0,0 +1,51 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku, Unit};
+
+    #[test]
+    fn test_swordfish_cols() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "006020000000901306730000051903200600060010080008005109640000012305102000000080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_swordfish();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::Swordfish);
+        let removals = result.removals;
+        assert_eq!(removals.unit, Some(Unit::Column));
+        assert_eq!(removals.unit_index, Some(vec![0, 1, 8]));
+        assert_eq!(removals.cover_unit, Some(Unit::Row));
+        assert_eq!(removals.cover_unit_index, Some(vec![0, 1, 7]));
+        assert_eq!(removals.candidates_affected.len(), 7);
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 4);
+        for (row, col) in [(0, 3), (0, 5), (0, 6), (7, 6)] {
+            assert!(candidates.contains(&Candidate { row, col, num: 8 }));
+        }
+    }
+
+    #[test]
+    fn test_jellyfish_cols() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "106020008804901306730800051913208605560419080408005109640000812385102000201080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_jellyfish();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::Jellyfish);
+        let removals = result.removals;
+        assert_eq!(removals.unit, Some(Unit::Column));
+        assert_eq!(removals.unit_index, Some(vec![3, 4, 5, 7]));
+        assert_eq!(removals.cover_unit, Some(Unit::Row));
+        assert_eq!(removals.cover_unit_index, Some(vec![0, 5, 6, 8]));
+        assert_eq!(removals.candidates_affected.len(), 11);
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 8,
+            col: 8,
+            num: 3
+        }));
+    }
+}
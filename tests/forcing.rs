@@ -0,0 +1,79 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku, Unit};
+
+    #[test]
+    fn test_cell_forcing_chain() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "050200600030000100006000004000020067000965000640071520000002006900003001400500700",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_cell_forcing_chain();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::CellForcingChain);
+        let removals = result.removals;
+        assert_eq!(
+            removals.candidates_affected,
+            vec![
+                Candidate {
+                    row: 3,
+                    col: 5,
+                    num: 4
+                },
+                Candidate {
+                    row: 3,
+                    col: 5,
+                    num: 8
+                }
+            ]
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 3,
+            col: 6,
+            num: 4
+        }));
+    }
+
+    #[test]
+    fn test_unit_forcing_chain() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "804100009729000050006789000010000060603020780085000020000000000070400000000030042",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_unit_forcing_chain();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::UnitForcingChain);
+        let removals = result.removals;
+        assert_eq!(removals.unit, Some(Unit::Row));
+        assert_eq!(removals.unit_index, Some(vec![0]));
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 0,
+            col: 6,
+            num: 3
+        }));
+    }
+
+    #[test]
+    fn test_contradiction_forcing_net() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "100007090030020008009600500005300900010080002600004000300000010041000007007000300",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_contradiction_forcing_net();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::ContradictionForcingNet);
+        let candidates = result.removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 6,
+            col: 5,
+            num: 9
+        }));
+    }
+}
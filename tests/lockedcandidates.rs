@@ -0,0 +1,22 @@
+mod tests {
+    use rate_my_sudoku::{Strategy, Sudoku, Unit};
+
+    #[test]
+    fn test_locked_triple() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "006020000000901306730000051903200600060010080008005109640000012305102000000080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_locked_pair();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::LockedPair);
+        assert_eq!(result.removals.unit, Some(Unit::Box));
+        assert_eq!(result.removals.unit_index, Some(vec![1]));
+        let mut solution = sudoku.clone();
+        assert!(solution.solve_by_backtracking());
+        for candidate in &result.removals.candidates_about_to_be_removed {
+            assert_ne!(solution.board[candidate.row][candidate.col], candidate.num);
+        }
+    }
+}
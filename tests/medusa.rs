@@ -0,0 +1,62 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    #[test]
+    fn test_3d_medusa() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000200009070610050060003204050000090000000700003006480645000030937860000821500000",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_3d_medusa();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::Medusa3D);
+        let removals = result.removals;
+        // Both colors are reported, spanning several digits
+        let colored = &removals.colored_candidates;
+        assert!(colored.iter().any(|(_, color)| *color == 0));
+        assert!(colored.iter().any(|(_, color)| *color == 1));
+        let mut nums: Vec<u8> = colored.iter().map(|(candidate, _)| candidate.num).collect();
+        nums.sort();
+        nums.dedup();
+        assert_eq!(nums, vec![1, 5, 7]);
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 8,
+            col: 7,
+            num: 7
+        }));
+    }
+
+    #[test]
+    fn test_3d_medusa_false_color() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000172948001894536948536271004051080009028614000049020852463097316987452497215863",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_3d_medusa();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::Medusa3D);
+        let removals = result.removals;
+        // The whole color that leads to a contradiction is removed
+        let colored = &removals.colored_candidates;
+        let (_, color) = colored
+            .iter()
+            .find(|(candidate, _)| (candidate.row, candidate.col, candidate.num) == (0, 0, 5))
+            .expect("Removed candidate is colored");
+        let false_color: Vec<Candidate> = colored
+            .iter()
+            .filter(|(_, other)| other == color)
+            .map(|(candidate, _)| *candidate)
+            .collect();
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 4);
+        assert_eq!(false_color.len(), 4);
+        for (row, col, num) in [(0, 0, 5), (0, 1, 6), (0, 2, 3), (5, 2, 5)] {
+            assert!(candidates.contains(&Candidate { row, col, num }));
+            assert!(false_color.contains(&Candidate { row, col, num }));
+        }
+    }
+}
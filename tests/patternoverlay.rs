@@ -0,0 +1,64 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Cell, Strategy, Sudoku};
+
+    #[test]
+    fn test_templates() {
+        let mut sudoku = Sudoku::new();
+        sudoku.calc_candidates();
+        for num in 1..=9 {
+            assert_eq!(sudoku.templates(num).len(), 46656);
+        }
+        let overlay = sudoku.template_overlay(1);
+        assert_eq!(overlay.templates, 46656);
+        assert!(overlay.cells_to_set.is_empty());
+        assert!(overlay.candidates_to_remove.is_empty());
+    }
+
+    #[test]
+    fn test_pattern_overlay() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "310605024050930016000020350040002083700503001030400060073856000524319678680247035",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_pattern_overlay();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::PatternOverlay);
+        let removals = result.removals;
+        assert!(removals.sets_cell.is_none());
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        assert!(candidates.contains(&Candidate {
+            row: 3,
+            col: 6,
+            num: 7
+        }));
+        assert!(candidates.contains(&Candidate {
+            row: 5,
+            col: 4,
+            num: 7
+        }));
+    }
+
+    #[test]
+    fn test_pattern_overlay_sets_cell() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "007109058180005009905827060018574930750398040493216080000981600801002093069703810",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let overlay = sudoku.template_overlay(2);
+        assert_eq!(overlay.cells_to_set, vec![(0, 1)]);
+        let result = sudoku.find_pattern_overlay();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::PatternOverlay);
+        assert_eq!(
+            result.removals.sets_cell,
+            Some(Cell {
+                row: 0,
+                col: 1,
+                num: 2
+            })
+        );
+    }
+}
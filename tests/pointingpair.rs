@@ -108,4 +108,36 @@ mod tests {
             num: 3
         }));
     }
+
+    #[test]
+    fn test_pointing_triple_box() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "061004008340080290000000000100300024800910000700420010010000000000502070500071300",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_pointing_pair_in_boxes();
+        println!("{:?}", result);
+        assert_eq!(result.unit, Some(Unit::Box));
+        assert_eq!(result.unit_index, Some(vec![3]));
+        assert!(result.sets_cell.is_none());
+        let candidates_affected = result.candidates_affected;
+        assert_eq!(candidates_affected.len(), 3);
+        for row in 3..6 {
+            assert!(candidates_affected.contains(&Candidate {
+                row,
+                col: 2,
+                num: 6
+            }));
+        }
+        let removals = result.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 3);
+        for row in 6..9 {
+            assert!(removals.contains(&Candidate {
+                row,
+                col: 2,
+                num: 6
+            }));
+        }
+    }
 }
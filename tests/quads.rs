@@ -0,0 +1,74 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku, Unit};
+
+    #[test]
+    fn test_obvious_quad_row() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "006020000000901306730000051903200600060010080008005109640000012305102000000080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_obvious_quad();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::ObviousQuad);
+        assert_eq!(result.removals.unit, Some(Unit::Row));
+        assert_eq!(result.removals.unit_index, Some(vec![3]));
+        let removals = result.removals.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 2);
+        assert!(removals.contains(&Candidate {
+            row: 3,
+            col: 5,
+            num: 4
+        }));
+        assert!(removals.contains(&Candidate {
+            row: 3,
+            col: 5,
+            num: 7
+        }));
+        let candidates_affected = result.removals.candidates_affected;
+        assert_eq!(candidates_affected.len(), 10);
+        for col in [1, 4, 7, 8] {
+            assert!(candidates_affected.contains(&Candidate {
+                row: 3,
+                col,
+                num: 7
+            }));
+        }
+    }
+
+    #[test]
+    fn test_hidden_quad_box() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "106020000800901306730000051913208605560019080408005109640000012385102000200080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_hidden_quad_in_boxes();
+        println!("{:?}", result);
+        assert_eq!(result.unit, Some(Unit::Box));
+        assert_eq!(result.unit_index, Some(vec![1]));
+        assert!(result.sets_cell.is_none());
+        let removals = result.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 5);
+        for (row, col, num) in [(0, 3, 4), (0, 5, 4), (1, 4, 4), (2, 3, 4), (2, 3, 6)] {
+            assert!(removals.contains(&Candidate { row, col, num }));
+        }
+        let candidates_affected = result.candidates_affected;
+        assert_eq!(candidates_affected.len(), 9);
+        for (row, col, num) in [(0, 3, 8), (0, 5, 3), (1, 4, 5), (2, 3, 8)] {
+            assert!(candidates_affected.contains(&Candidate { row, col, num }));
+        }
+    }
+
+    #[test]
+    fn test_hidden_quad_none() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "006020000000901306730000051903200600060010080008005109640000012305102000000080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_hidden_quad();
+        println!("{:?}", result);
+        assert!(!result.removals.will_remove_candidates());
+    }
+}
@@ -0,0 +1,90 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    const PUZZLE: &str =
+        "000008009600000324240000005006000001050031000800000002500364000000090060000700090";
+
+    #[test]
+    fn test_effort_accumulated_once_per_step() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(PUZZLE).expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.next_step();
+        assert_eq!(sudoku.next_step().strategy, result.strategy);
+        assert!(sudoku.rating.is_empty());
+        let resolution = sudoku.apply(&result);
+        let count = result.removals.candidates_about_to_be_removed.len();
+        let rating = sudoku.rating[&result.strategy];
+        assert_eq!(rating.steps, 1);
+        assert_eq!(rating.count, count);
+        assert_eq!(
+            rating.effort,
+            count as i32 * sudoku.strategy_effort(result.strategy).unwrap()
+        );
+        assert_eq!(resolution.effort, rating.effort);
+        sudoku.undo();
+        assert!(sudoku.rating.is_empty());
+    }
+
+    #[test]
+    fn test_only_present_candidates_are_counted() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(PUZZLE).expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let mut result = sudoku.next_step();
+        let present = result.removals.candidates_about_to_be_removed.len();
+        let gone = (1..=9)
+            .map(|num| Candidate {
+                row: 0,
+                col: 0,
+                num,
+            })
+            .find(|candidate| !sudoku.candidates[0][0].contains(candidate.num))
+            .expect("Cell has a digit that is not a candidate");
+        result.removals.candidates_about_to_be_removed.insert(gone);
+        let resolution = sudoku.apply(&result);
+        assert_eq!(resolution.nums_removed, present);
+        let rating = sudoku.rating[&result.strategy];
+        assert_eq!(rating.count, present);
+        assert_eq!(
+            rating.effort,
+            present as i32 * sudoku.strategy_effort(result.strategy).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rating_report() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(PUZZLE).expect("Failed to create Sudoku from string");
+        let path = sudoku.solve_human_like();
+        let report = sudoku.rating_report();
+        assert!(report.solved);
+        let count: usize = path.iter().map(|step| step.candidates_removed.len()).sum();
+        assert_eq!(report.count(), count);
+        assert_eq!(
+            report.total_effort(),
+            path.iter().map(|step| step.effort).sum::<i32>()
+        );
+        assert_eq!(report.effort(), report.total_effort() as f64 / count as f64);
+        let efforts: Vec<i32> = report
+            .strategies
+            .iter()
+            .map(|entry| entry.rating.unit_effort)
+            .collect();
+        assert!(efforts.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(report.hardest_strategy(), Some(Strategy::ObviousTriplet));
+        assert!(report.to_string().contains("Difficulty"));
+        sudoku.config.efforts.insert(Strategy::ObviousTriplet, 1000);
+        assert_eq!(sudoku.rating_report(), report);
+    }
+
+    #[test]
+    fn test_empty_rating_report() {
+        let sudoku: Sudoku =
+            Sudoku::from_string(PUZZLE).expect("Failed to create Sudoku from string");
+        let report = sudoku.rating_report();
+        assert!(!report.solved);
+        assert_eq!(report.count(), 0);
+        assert_eq!(report.effort(), 0.0);
+    }
+}
@@ -0,0 +1,108 @@
+mod tests {
+    use rate_my_sudoku::{SolvingStrategy, Strategy, StrategyRegistry, StrategyResult, Sudoku};
+    use std::sync::Arc;
+
+    /// Obvious singles, reported as a technique of its own
+    struct MySingle;
+
+    impl SolvingStrategy for MySingle {
+        fn strategy(&self) -> Strategy {
+            Strategy::Custom("My Single")
+        }
+
+        fn name(&self) -> &str {
+            "My Single"
+        }
+
+        fn effort(&self) -> i32 {
+            1
+        }
+
+        fn find_one(&self, sudoku: &Sudoku) -> StrategyResult {
+            let result = sudoku.find_obvious_single();
+            StrategyResult {
+                strategy: self.strategy(),
+                removals: result.removals,
+            }
+        }
+    }
+
+    #[test]
+    fn test_builtin_registry_is_ordered_by_effort() {
+        let registry = StrategyRegistry::builtin();
+        assert!(!registry.is_empty());
+        let efforts: Vec<i32> = registry.iter().map(|strategy| strategy.effort()).collect();
+        assert!(efforts.windows(2).all(|pair| pair[0] <= pair[1]));
+        let xwing = registry.get(Strategy::XWing).expect("X-Wing is built in");
+        assert_eq!(xwing.name(), "X-Wing");
+    }
+
+    #[test]
+    fn test_custom_strategy() {
+        let mut registry = StrategyRegistry::builtin();
+        registry.register(MySingle);
+        assert_eq!(
+            registry.iter().next().map(|strategy| strategy.strategy()),
+            Some(Strategy::Custom("My Single"))
+        );
+        assert_eq!(registry.effort(Strategy::Custom("My Single")), Some(1));
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.registry = Arc::new(registry);
+        assert!(sudoku.solve_human_like().solved);
+        assert!(sudoku.rating.contains_key(&Strategy::Custom("My Single")));
+        assert!(!sudoku.rating.contains_key(&Strategy::ObviousSingle));
+    }
+
+    #[test]
+    fn test_unregister() {
+        let mut registry = StrategyRegistry::builtin();
+        let len = registry.len();
+        registry.unregister(Strategy::BowmansBingo);
+        assert_eq!(registry.len(), len - 1);
+        assert!(registry.get(Strategy::BowmansBingo).is_none());
+        assert_eq!(
+            registry.effort(Strategy::BowmansBingo),
+            StrategyRegistry::builtin()
+                .get(Strategy::BowmansBingo)
+                .map(|strategy| strategy.effort())
+        );
+    }
+
+    #[test]
+    fn test_empty_registry() {
+        let mut registry = StrategyRegistry::empty();
+        assert!(registry.is_empty());
+        assert_eq!(
+            registry.effort(Strategy::XWing),
+            StrategyRegistry::builtin()
+                .get(Strategy::XWing)
+                .map(|strategy| strategy.effort())
+        );
+        registry.register(MySingle);
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_unregistered_custom_strategy() {
+        assert_eq!(
+            StrategyRegistry::builtin().effort(Strategy::Custom("My Single")),
+            None
+        );
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = StrategyResult {
+            strategy: Strategy::Custom("My Single"),
+            removals: sudoku.find_obvious_single().removals,
+        };
+        assert_eq!(sudoku.strategy_effort(result.strategy), None);
+        assert_eq!(sudoku.step_effort(&result), None);
+        assert_eq!(sudoku.apply(&result).effort, 0);
+        assert!(sudoku.rating.is_empty());
+    }
+}
@@ -1,5 +1,5 @@
 mod tests {
-    use rate_my_sudoku::Sudoku;
+    use rate_my_sudoku::{Sudoku, Unit};
 
     #[test]
     fn test_skyscraper_row() {
@@ -11,6 +11,14 @@ mod tests {
         let result = sudoku.find_skyscraper_in_rows();
         println!("{:?}", result);
         assert!(result.sets_cell.is_none());
+        assert_eq!(result.unit, Some(Unit::Row));
+        assert_eq!(result.candidates_affected.len(), 4);
+        assert!(result.will_remove_candidates());
+        let mut solution = sudoku.clone();
+        assert!(solution.solve_by_backtracking());
+        for candidate in &result.candidates_about_to_be_removed {
+            assert_ne!(solution.board[candidate.row][candidate.col], candidate.num);
+        }
     }
 
     #[test]
@@ -23,5 +31,13 @@ mod tests {
         let result = sudoku.find_skyscraper_in_cols();
         println!("{:?}", result);
         assert!(result.sets_cell.is_none());
+        assert_eq!(result.unit, Some(Unit::Column));
+        assert_eq!(result.candidates_affected.len(), 4);
+        assert!(result.will_remove_candidates());
+        let mut solution = sudoku.clone();
+        assert!(solution.solve_by_backtracking());
+        for candidate in &result.candidates_about_to_be_removed {
+            assert_ne!(solution.board[candidate.row][candidate.col], candidate.num);
+        }
     }
 }
@@ -0,0 +1,69 @@
+mod tests {
+    use rate_my_sudoku::{Strategy, Sudoku};
+
+    const PUZZLE: &str =
+        "000008009600000324240000005006000001050031000800000002500364000000090060000700090";
+
+    #[test]
+    fn test_solve_path() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(PUZZLE).expect("Failed to create Sudoku from string");
+        let path = sudoku.solve_human_like();
+        assert!(path.solved);
+        assert_eq!(path.puzzle, PUZZLE);
+        let steps_setting_cells = path.iter().filter(|step| step.sets_cell.is_some()).count();
+        assert_eq!(steps_setting_cells, PUZZLE.matches('0').count());
+        for (step, next) in path.steps.iter().zip(path.steps.iter().skip(1)) {
+            assert_eq!(step.hash_after, next.hash_before);
+            assert_ne!(step.hash_before, step.hash_after);
+        }
+        assert_eq!(
+            path.steps.last().unwrap().hash_after,
+            sudoku.snapshot_hash()
+        );
+        assert!(
+            path.iter()
+                .any(|step| step.strategy == Strategy::LockedPair)
+        );
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(PUZZLE).expect("Failed to create Sudoku from string");
+        let mut path = sudoku.solve_human_like();
+        let replayed = path.replay(path.len()).expect("Failed to replay path");
+        assert!(replayed.is_solved());
+        assert_eq!(replayed, sudoku);
+        let halfway = path.replay(path.len() / 2).expect("Failed to replay path");
+        assert!(halfway.unsolved());
+        assert_eq!(
+            halfway.snapshot_hash(),
+            path.steps[path.len() / 2 - 1].hash_after
+        );
+        path.steps.remove(0);
+        assert!(path.replay(path.len()).is_err());
+    }
+
+    #[test]
+    fn test_export() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(PUZZLE).expect("Failed to create Sudoku from string");
+        let path = sudoku.solve_human_like();
+        let json: serde_json::Value =
+            serde_json::from_str(&path.to_json()).expect("Failed to parse JSON");
+        assert_eq!(json["puzzle"], PUZZLE);
+        assert_eq!(json["solved"], true);
+        let steps = json["steps"].as_array().unwrap();
+        assert_eq!(steps.len(), path.len());
+        assert_eq!(steps[0]["strategy"], path.steps[0].strategy.to_string());
+    }
+
+    #[test]
+    fn test_snapshot_hash_is_stable() {
+        let mut sudoku: Sudoku =
+            Sudoku::from_string(PUZZLE).expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        assert_eq!(sudoku.snapshot_hash(), 13224055487997157247);
+    }
+}
@@ -1,6 +1,5 @@
 mod tests {
-    use rate_my_sudoku::Sudoku;
-    use std::collections::HashSet;
+    use rate_my_sudoku::{CandidateSet, Sudoku};
 
     #[test]
     fn test_sudoku_serialize() {
@@ -16,7 +15,7 @@ mod tests {
 
     #[test]
     fn test_sudoku_deserialize() {
-        let sudoku: Sudoku = Sudoku::from_json(&"{\"board\":[[3,1,8,0,0,5,4,0,6],[0,0,0,6,0,3,8,1,0],[0,0,6,0,8,0,5,0,3],[8,6,4,9,5,2,1,3,7],[1,2,3,4,7,6,9,5,8],[7,9,5,3,1,8,2,6,4],[0,3,0,5,0,0,7,8,0],[0,0,0,0,0,7,3,0,5],[0,0,0,0,3,9,6,4,1]],\"candidates\":[[[],[],[],[2,7],[2,9],[],[],[7,2,9],[]],[[5,9,2,4],[7,5,4],[7,9,2],[],[2,4,9],[],[],[],[9,2]],[[9,2,4],[7,4],[],[1,7,2],[],[1,4],[],[7,9,2],[]],[[],[],[],[],[],[],[],[],[]],[[],[],[],[],[],[],[],[],[]],[[],[],[],[],[],[],[],[],[]],[[2,9,4,6],[],[1,2,9],[],[2,6,4],[1,4],[],[],[2,9]],[[6,2,4,9],[4,8],[2,9,1],[2,8,1],[6,2,4],[],[],[2,9],[]],[[2,5],[7,8,5],[2,7],[8,2],[],[],[],[],[]]]}".to_string()).expect("Failed to create Sudoku from JSON string");
+        let sudoku: Sudoku = Sudoku::from_json("{\"board\":[[3,1,8,0,0,5,4,0,6],[0,0,0,6,0,3,8,1,0],[0,0,6,0,8,0,5,0,3],[8,6,4,9,5,2,1,3,7],[1,2,3,4,7,6,9,5,8],[7,9,5,3,1,8,2,6,4],[0,3,0,5,0,0,7,8,0],[0,0,0,0,0,7,3,0,5],[0,0,0,0,3,9,6,4,1]],\"candidates\":[[[],[],[],[2,7],[2,9],[],[],[7,2,9],[]],[[5,9,2,4],[7,5,4],[7,9,2],[],[2,4,9],[],[],[],[9,2]],[[9,2,4],[7,4],[],[1,7,2],[],[1,4],[],[7,9,2],[]],[[],[],[],[],[],[],[],[],[]],[[],[],[],[],[],[],[],[],[]],[[],[],[],[],[],[],[],[],[]],[[2,9,4,6],[],[1,2,9],[],[2,6,4],[1,4],[],[],[2,9]],[[6,2,4,9],[4,8],[2,9,1],[2,8,1],[6,2,4],[],[],[2,9],[]],[[2,5],[7,8,5],[2,7],[8,2],[],[],[],[],[]]]}").expect("Failed to create Sudoku from JSON string");
         assert_eq!(
             sudoku.to_board_string(),
             "318005406000603810006080503864952137123476958795318264030500780000007305000039641"
@@ -39,108 +38,144 @@ mod tests {
             sudoku.candidates,
             [
                 [
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::from([2, 7]),
-                    HashSet::from([2, 9]),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::from([2, 9, 7]),
-                    HashSet::new()
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::from([2, 7]),
+                    CandidateSet::from([2, 9]),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::from([2, 9, 7]),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::from([5, 9, 2, 4]),
-                    HashSet::from([4, 7, 5]),
-                    HashSet::from([7, 2, 9]),
-                    HashSet::new(),
-                    HashSet::from([2, 4, 9]),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::from([9, 2])
+                    CandidateSet::from([5, 9, 2, 4]),
+                    CandidateSet::from([4, 7, 5]),
+                    CandidateSet::from([7, 2, 9]),
+                    CandidateSet::new(),
+                    CandidateSet::from([2, 4, 9]),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::from([9, 2])
                 ],
                 [
-                    HashSet::from([9, 4, 2]),
-                    HashSet::from([4, 7]),
-                    HashSet::new(),
-                    HashSet::from([1, 7, 2]),
-                    HashSet::new(),
-                    HashSet::from([1, 4]),
-                    HashSet::new(),
-                    HashSet::from([7, 9, 2]),
-                    HashSet::new()
+                    CandidateSet::from([9, 4, 2]),
+                    CandidateSet::from([4, 7]),
+                    CandidateSet::new(),
+                    CandidateSet::from([1, 7, 2]),
+                    CandidateSet::new(),
+                    CandidateSet::from([1, 4]),
+                    CandidateSet::new(),
+                    CandidateSet::from([7, 9, 2]),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new()
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new()
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new()
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::from([2, 9, 4, 6]),
-                    HashSet::new(),
-                    HashSet::from([9, 2, 1]),
-                    HashSet::new(),
-                    HashSet::from([6, 2, 4]),
-                    HashSet::from([1, 4]),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::from([9, 2])
+                    CandidateSet::from([2, 9, 4, 6]),
+                    CandidateSet::new(),
+                    CandidateSet::from([9, 2, 1]),
+                    CandidateSet::new(),
+                    CandidateSet::from([6, 2, 4]),
+                    CandidateSet::from([1, 4]),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::from([9, 2])
                 ],
                 [
-                    HashSet::from([6, 2, 9, 4]),
-                    HashSet::from([8, 4]),
-                    HashSet::from([1, 9, 2]),
-                    HashSet::from([2, 8, 1]),
-                    HashSet::from([2, 4, 6]),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::from([9, 2]),
-                    HashSet::new()
+                    CandidateSet::from([6, 2, 9, 4]),
+                    CandidateSet::from([8, 4]),
+                    CandidateSet::from([1, 9, 2]),
+                    CandidateSet::from([2, 8, 1]),
+                    CandidateSet::from([2, 4, 6]),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::from([9, 2]),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::from([2, 5]),
-                    HashSet::from([7, 5, 8]),
-                    HashSet::from([7, 2]),
-                    HashSet::from([8, 2]),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new()
+                    CandidateSet::from([2, 5]),
+                    CandidateSet::from([7, 5, 8]),
+                    CandidateSet::from([7, 2]),
+                    CandidateSet::from([8, 2]),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new()
                 ]
             ]
         );
     }
 
+    #[test]
+    fn test_sudoku_deserialize_invalid_digits() {
+        let empty_row = "[0,0,0,0,0,0,0,0,0]";
+        let empty_candidates = "[[],[],[],[],[],[],[],[],[]]";
+        let board = [empty_row; 9].join(",");
+        let mut candidates = [empty_candidates; 9];
+        candidates[0] = "[[1,20],[],[],[],[],[],[],[],[]]";
+        let json = format!(
+            "{{\"board\":[{}],\"candidates\":[{}]}}",
+            board,
+            candidates.join(",")
+        );
+        assert!(Sudoku::from_json(&json).is_err());
+        candidates[0] = "[[0],[],[],[],[],[],[],[],[]]";
+        let json = format!(
+            "{{\"board\":[{}],\"candidates\":[{}]}}",
+            board,
+            candidates.join(",")
+        );
+        assert!(Sudoku::from_json(&json).is_err());
+        let mut rows = [empty_row; 9];
+        rows[0] = "[10,0,0,0,0,0,0,0,0]";
+        let json = format!(
+            "{{\"board\":[{}],\"candidates\":[{}]}}",
+            rows.join(","),
+            [empty_candidates; 9].join(",")
+        );
+        assert!(Sudoku::from_json(&json).is_err());
+        let json = format!(
+            "{{\"board\":[{}],\"candidates\":[{}]}}",
+            board,
+            [empty_candidates; 9].join(",")
+        );
+        assert!(Sudoku::from_json(&json).is_ok());
+    }
+
     #[test]
     fn test_sudoku_calc_candidates() {
         let mut sudoku: Sudoku = Sudoku::from_string(
@@ -152,103 +187,103 @@ mod tests {
             sudoku.candidates,
             [
                 [
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::from([2, 7]),
-                    HashSet::from([2, 9]),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::from([2, 9, 7]),
-                    HashSet::new()
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::from([2, 7]),
+                    CandidateSet::from([2, 9]),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::from([2, 9, 7]),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::from([5, 9, 2, 4]),
-                    HashSet::from([4, 7, 5]),
-                    HashSet::from([7, 2, 9]),
-                    HashSet::new(),
-                    HashSet::from([2, 4, 9]),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::from([9, 2])
+                    CandidateSet::from([5, 9, 2, 4]),
+                    CandidateSet::from([4, 7, 5]),
+                    CandidateSet::from([7, 2, 9]),
+                    CandidateSet::new(),
+                    CandidateSet::from([2, 4, 9]),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::from([9, 2])
                 ],
                 [
-                    HashSet::from([9, 4, 2]),
-                    HashSet::from([4, 7]),
-                    HashSet::new(),
-                    HashSet::from([1, 7, 2]),
-                    HashSet::new(),
-                    HashSet::from([1, 4]),
-                    HashSet::new(),
-                    HashSet::from([7, 9, 2]),
-                    HashSet::new()
+                    CandidateSet::from([9, 4, 2]),
+                    CandidateSet::from([4, 7]),
+                    CandidateSet::new(),
+                    CandidateSet::from([1, 7, 2]),
+                    CandidateSet::new(),
+                    CandidateSet::from([1, 4]),
+                    CandidateSet::new(),
+                    CandidateSet::from([7, 9, 2]),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new()
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new()
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new()
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::from([2, 9, 4, 6]),
-                    HashSet::new(),
-                    HashSet::from([9, 2, 1]),
-                    HashSet::new(),
-                    HashSet::from([6, 2, 4]),
-                    HashSet::from([1, 4]),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::from([9, 2])
+                    CandidateSet::from([2, 9, 4, 6]),
+                    CandidateSet::new(),
+                    CandidateSet::from([9, 2, 1]),
+                    CandidateSet::new(),
+                    CandidateSet::from([6, 2, 4]),
+                    CandidateSet::from([1, 4]),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::from([9, 2])
                 ],
                 [
-                    HashSet::from([6, 2, 9, 4]),
-                    HashSet::from([8, 4]),
-                    HashSet::from([1, 9, 2]),
-                    HashSet::from([2, 8, 1]),
-                    HashSet::from([2, 4, 6]),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::from([9, 2]),
-                    HashSet::new()
+                    CandidateSet::from([6, 2, 9, 4]),
+                    CandidateSet::from([8, 4]),
+                    CandidateSet::from([1, 9, 2]),
+                    CandidateSet::from([2, 8, 1]),
+                    CandidateSet::from([2, 4, 6]),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::from([9, 2]),
+                    CandidateSet::new()
                 ],
                 [
-                    HashSet::from([2, 5]),
-                    HashSet::from([7, 5, 8]),
-                    HashSet::from([7, 2]),
-                    HashSet::from([8, 2]),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new(),
-                    HashSet::new()
+                    CandidateSet::from([2, 5]),
+                    CandidateSet::from([7, 5, 8]),
+                    CandidateSet::from([7, 2]),
+                    CandidateSet::from([8, 2]),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new(),
+                    CandidateSet::new()
                 ]
             ]
         );
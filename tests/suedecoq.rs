@@ -0,0 +1,49 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku, Unit};
+
+    #[test]
+    fn test_sue_de_coq() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "215040709378002405469700023603004070107000034894007000032400007740980302080273040",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_sue_de_coq();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::SueDeCoq);
+        let removals = result.removals;
+        assert_eq!(removals.unit, Some(Unit::Box));
+        assert_eq!(removals.unit_index, Some(vec![6]));
+        // The intersection cells, then the line cells, then the box cells
+        let cells: Vec<(usize, usize, usize)> = removals
+            .colored_candidates
+            .iter()
+            .map(|(candidate, color)| (candidate.row, candidate.col, *color))
+            .collect();
+        assert_eq!(
+            cells,
+            vec![
+                (8, 0, 0),
+                (8, 0, 0),
+                (8, 2, 0),
+                (8, 2, 0),
+                (8, 8, 1),
+                (8, 8, 1),
+                (6, 0, 2),
+                (6, 0, 2)
+            ]
+        );
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        assert!(candidates.contains(&Candidate {
+            row: 8,
+            col: 6,
+            num: 1
+        }));
+        assert!(candidates.contains(&Candidate {
+            row: 8,
+            col: 6,
+            num: 6
+        }));
+    }
+}
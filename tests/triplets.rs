@@ -326,4 +326,72 @@ mod tests {
 
         assert_eq!(removals.len(), 8);
     }
+
+    #[test]
+    fn test_hidden_triplet_row() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "600215937531079000927030001000092070309000012000083049750001090413950700890307100",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_hidden_triplet_in_rows();
+        println!("{:?}", result);
+        assert_eq!(result.unit, Some(Unit::Row));
+        assert_eq!(result.unit_index, Some(vec![4]));
+        assert!(result.sets_cell.is_none());
+        let removals = result.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 5);
+        for (col, num) in [(1, 4), (1, 6), (3, 4), (3, 6), (6, 6)] {
+            assert!(removals.contains(&Candidate { row: 4, col, num }));
+        }
+        let candidates_affected = result.candidates_affected;
+        assert_eq!(candidates_affected.len(), 6);
+        for (col, num) in [(1, 7), (1, 8), (3, 5), (3, 7), (6, 5), (6, 8)] {
+            assert!(candidates_affected.contains(&Candidate { row: 4, col, num }));
+        }
+    }
+
+    #[test]
+    fn test_hidden_triplet_col() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "600215937531079000927030001000090070309000012000083049750001090413950700890307100",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_hidden_triplet_in_cols();
+        println!("{:?}", result);
+        assert_eq!(result.unit, Some(Unit::Column));
+        assert_eq!(result.unit_index, Some(vec![2]));
+        let removals = result.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 4);
+        for (row, num) in [(3, 2), (3, 6), (5, 2), (5, 6)] {
+            assert!(removals.contains(&Candidate { row, col: 2, num }));
+        }
+        let candidates_affected = result.candidates_affected;
+        assert_eq!(candidates_affected.len(), 6);
+        for (row, num) in [(0, 4), (0, 8), (3, 4), (3, 5), (3, 8), (5, 5)] {
+            assert!(candidates_affected.contains(&Candidate { row, col: 2, num }));
+        }
+    }
+
+    #[test]
+    fn test_hidden_triplet_box() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000005907530079000927000001000090070309000012000083049750001090413950700890307100",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_hidden_triplet();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::HiddenTriplet);
+        let result = sudoku.find_hidden_triplet_in_boxes();
+        assert_eq!(result.unit, Some(Unit::Box));
+        assert_eq!(result.unit_index, Some(vec![4]));
+        let removals = result.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 7);
+        for (row, num) in [(3, 2), (3, 4), (3, 6), (4, 4), (4, 6), (5, 2), (5, 6)] {
+            assert!(removals.contains(&Candidate { row, col: 3, num }));
+        }
+        assert_eq!(result.candidates_affected.len(), 7);
+    }
 }
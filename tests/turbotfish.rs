@@ -0,0 +1,53 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    #[test]
+    fn test_turbot_fish() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "006020000000901306730000051903200600060010080008005109640000012305102000000080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_turbot_fish();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::TurbotFish);
+        let removals = result.removals;
+        // Strong links in columns 7 and 8, connected in box 5
+        let candidates_affected = removals.candidates_affected;
+        assert_eq!(candidates_affected.len(), 4);
+        for (row, col) in [(8, 7), (5, 7), (4, 8), (8, 8)] {
+            assert!(candidates_affected.contains(&Candidate { row, col, num: 3 }));
+        }
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        for (row, col) in [(8, 3), (8, 5)] {
+            assert!(candidates.contains(&Candidate { row, col, num: 3 }));
+        }
+    }
+
+    #[test]
+    fn test_two_string_kite() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "106020000000901306730000051913200600060010080008005109640000012305102000200080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_two_string_kite();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::TwoStringKite);
+        let removals = result.removals;
+        // Strong links in row 4 and column 7, connected in box 5
+        let candidates_affected = removals.candidates_affected;
+        assert_eq!(candidates_affected.len(), 4);
+        for (row, col) in [(4, 2), (4, 6), (5, 7), (1, 7)] {
+            assert!(candidates_affected.contains(&Candidate { row, col, num: 2 }));
+        }
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 1,
+            col: 2,
+            num: 2
+        }));
+    }
+}
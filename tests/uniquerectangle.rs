@@ -0,0 +1,246 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku, Unit};
+
+    #[test]
+    fn test_unique_rectangle_type_1() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000400308283009504046038912108674200030105840604890701415987623369000487800346100",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_unique_rectangle_type_1();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::UniqueRectangleType1);
+        let removals = result.removals;
+        assert_eq!(removals.candidates_affected.len(), 8);
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        for num in [5, 9] {
+            assert!(candidates.contains(&Candidate {
+                row: 3,
+                col: 7,
+                num
+            }));
+        }
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_2() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "030245010015030080000070653107650030053097160060312574572080340380520790491763825",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_unique_rectangle_type_2();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::UniqueRectangleType2);
+        let candidates = result.removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        for col in [0, 2] {
+            assert!(candidates.contains(&Candidate {
+                row: 2,
+                col,
+                num: 8
+            }));
+        }
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_3() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "030205010015030080000070653107650030053097160060312574572080340380520790091703825",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_unique_rectangle_type_3();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::UniqueRectangleType3);
+        let removals = result.removals;
+        assert_eq!(removals.unit, Some(Unit::Row));
+        assert_eq!(removals.unit_index, Some(vec![2]));
+        // The rectangle's corners plus the cell forming the naked subset
+        assert_eq!(removals.candidates_affected.len(), 10);
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        for col in [3, 5] {
+            assert!(candidates.contains(&Candidate {
+                row: 2,
+                col,
+                num: 4
+            }));
+        }
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_4() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "100968502900352160652741389507089200800205490209010805300120950495806700701590608",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_unique_rectangle_type_4();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::UniqueRectangleType4);
+        let removals = result.removals;
+        assert_eq!(removals.unit, Some(Unit::Box));
+        assert_eq!(removals.unit_index, Some(vec![3]));
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        for row in [3, 5] {
+            assert!(candidates.contains(&Candidate {
+                row,
+                col: 1,
+                num: 6
+            }));
+        }
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_6() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "249700615813956472576214398004875201058192740721643589407321950002500104135469827",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_unique_rectangle_type_6();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::UniqueRectangleType6);
+        let candidates = result.removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        for (row, col) in [(3, 0), (7, 1)] {
+            assert!(candidates.contains(&Candidate { row, col, num: 9 }));
+        }
+    }
+
+    /// An empty board with the given candidates, all other cells without candidates
+    fn board_with_candidates(cells: &[((usize, usize), &[u8])]) -> Sudoku {
+        let mut candidates = vec![vec![String::from("[]"); 9]; 9];
+        for &((row, col), nums) in cells {
+            candidates[row][col] = format!("{:?}", nums);
+        }
+        let rows: Vec<String> = candidates
+            .iter()
+            .map(|row| format!("[{}]", row.join(",")))
+            .collect();
+        let board = ["[0,0,0,0,0,0,0,0,0]"; 9].join(",");
+        let json = format!(
+            "{{\"board\":[{}],\"candidates\":[{}]}}",
+            board,
+            rows.join(",")
+        );
+        Sudoku::from_json(&json).expect("Failed to create Sudoku from JSON string")
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_5() {
+        // One corner (1, 6) holds only 5 and 6, the other three also hold a 9
+        let sudoku: Sudoku = Sudoku::from_json("{\"board\":[[0,0,7,0,1,2,0,4,3],[4,0,0,3,7,0,0,1,2],[2,1,3,0,4,5,7,8,0],[6,7,1,5,0,0,3,2,4],[0,0,0,1,2,4,0,6,7],[0,2,4,7,3,6,1,5,0],[1,4,2,0,0,7,0,3,5],[0,0,0,2,0,1,4,7,0],[7,8,6,4,5,3,2,9,1]],\"candidates\":[[[5,8,9],[5,6,9],[],[6,8,9],[],[],[5,6,9],[],[]],[[],[5,6,9],[5,8,9],[],[],[8,9],[5,6],[],[]],[[],[],[],[6,9],[],[],[],[],[6,9]],[[],[],[],[],[8,9],[8,9],[],[],[]],[[3,5,8,9],[3,5,9],[5,8,9],[],[],[],[8,9],[],[]],[[8,9],[],[],[],[],[],[],[],[8,9]],[[],[],[],[8,9],[6,8,9],[],[6,8],[],[]],[[3,5,9],[3,5,9],[5,9],[],[6,8],[],[],[],[6,8]],[[],[],[],[],[],[],[],[],[]]]}")
+            .expect("Failed to create Sudoku from JSON string");
+        let result = sudoku.find_unique_rectangle_type_5();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::UniqueRectangleType5);
+        assert_eq!(result.removals.candidates_affected.len(), 8);
+        let candidates = result.removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 0,
+            col: 0,
+            num: 9
+        }));
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_5_diagonal() {
+        // (0, 0) and (1, 3) hold only 1 and 2, (0, 3) and (1, 0) also hold a 9
+        let sudoku = board_with_candidates(&[
+            ((0, 0), &[1, 2]),
+            ((0, 1), &[5, 9]),
+            ((0, 3), &[1, 2, 9]),
+            ((1, 0), &[1, 2, 9]),
+            ((1, 3), &[1, 2]),
+            ((1, 4), &[6, 9]),
+            ((2, 0), &[7, 9]),
+        ]);
+        let result = sudoku.find_unique_rectangle_type_5();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::UniqueRectangleType5);
+        let candidates = result.removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 2);
+        for (row, col) in [(0, 1), (1, 4)] {
+            assert!(candidates.contains(&Candidate { row, col, num: 9 }));
+        }
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_5_not_found() {
+        // The corners holding only 1 and 2 share a row: that's type 2, not type 5
+        let sudoku = board_with_candidates(&[
+            ((0, 0), &[1, 2]),
+            ((0, 3), &[1, 2]),
+            ((1, 0), &[1, 2, 9]),
+            ((1, 3), &[1, 2, 9]),
+            ((1, 4), &[6, 9]),
+        ]);
+        assert!(
+            !sudoku
+                .find_unique_rectangle_type_2()
+                .removals
+                .candidates_about_to_be_removed
+                .is_empty()
+        );
+        let result = sudoku.find_unique_rectangle_type_5();
+        assert!(!result.removals.will_remove_candidates());
+        // Different extra candidates in the other corners
+        let sudoku = board_with_candidates(&[
+            ((0, 0), &[1, 2]),
+            ((0, 1), &[5, 9]),
+            ((0, 3), &[1, 2, 9]),
+            ((1, 0), &[1, 2, 9]),
+            ((1, 3), &[1, 2, 8]),
+            ((1, 4), &[6, 9]),
+        ]);
+        let result = sudoku.find_unique_rectangle_type_5();
+        assert!(!result.removals.will_remove_candidates());
+    }
+
+    #[test]
+    fn test_hidden_unique_rectangle() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000400008283000004006038900108074200030105840004890701005980600360000487800046000",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_hidden_unique_rectangle();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::HiddenUniqueRectangle);
+        let candidates = result.removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 2,
+            col: 1,
+            num: 7
+        }));
+    }
+
+    #[test]
+    fn test_unique_rectangle_opt_out() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000400308283009504046038912108674200030105840604890701415987623369000487800346100",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        assert!(
+            sudoku
+                .all_possible_strategies()
+                .iter()
+                .any(|result| result.strategy == Strategy::UniqueRectangleType1)
+        );
+        sudoku.options.assume_unique_solution = false;
+        assert!(
+            !sudoku
+                .all_possible_strategies()
+                .iter()
+                .any(|result| result.strategy.requires_unique_solution())
+        );
+    }
+}
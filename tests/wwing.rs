@@ -0,0 +1,40 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    #[test]
+    fn test_wwing() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "006020000000901306730000051903200600060010080008005109640000012305102000000080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_wwing();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::WWing);
+        let removals = result.removals;
+        assert!(removals.sets_cell.is_none());
+        let candidates_affected = removals.candidates_affected;
+        assert_eq!(candidates_affected.len(), 6);
+        // The strong link on 2 in column 7 connects both bivalue cells
+        for (row, col, num) in [(1, 2, 4), (5, 0, 4), (1, 7, 2), (5, 7, 2)] {
+            assert!(candidates_affected.contains(&Candidate { row, col, num }));
+        }
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 3);
+        for (row, col) in [(0, 0), (1, 0), (4, 2)] {
+            assert!(candidates.contains(&Candidate { row, col, num: 4 }));
+        }
+    }
+
+    #[test]
+    fn test_wwing_none() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "061004008340080290000000000100300024800910000700420010010000000000502070500071300",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_wwing();
+        println!("{:?}", result);
+        assert!(!result.removals.will_remove_candidates());
+    }
+}
@@ -0,0 +1,70 @@
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    #[test]
+    fn test_xywing() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "106020008804901306730800051913208605560019080408005109640000812385102000201080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_xywing();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::XYWing);
+        let removals = result.removals;
+        assert!(removals.sets_cell.is_none());
+        // Pivot first, then the two pincers
+        let candidates_affected = removals.candidates_affected;
+        assert_eq!(candidates_affected.len(), 6);
+        for (i, (row, col, num)) in [
+            (1, 1, 2),
+            (1, 1, 5),
+            (1, 4, 5),
+            (1, 4, 7),
+            (5, 1, 2),
+            (5, 1, 7),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(candidates_affected[i], Candidate { row, col, num });
+        }
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 5,
+            col: 4,
+            num: 7
+        }));
+    }
+
+    #[test]
+    fn test_xyzwing() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "006020000000901306730000051903200600060010080008005109640000012305102000000080500",
+        )
+        .expect("Failed to create Sudoku from string");
+        sudoku.calc_candidates();
+        let result = sudoku.find_xyzwing();
+        println!("{:?}", result);
+        assert_eq!(result.strategy, Strategy::XYZWing);
+        let removals = result.removals;
+        let candidates_affected = removals.candidates_affected;
+        assert_eq!(candidates_affected.len(), 7);
+        // The pivot holds all three candidates
+        for num in [2, 4, 7] {
+            assert!(candidates_affected[..3].contains(&Candidate {
+                row: 4,
+                col: 2,
+                num
+            }));
+        }
+        let candidates = removals.candidates_about_to_be_removed;
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&Candidate {
+            row: 4,
+            col: 0,
+            num: 2
+        }));
+    }
+}